@@ -1,35 +1,82 @@
-use crate::inputs::{CalculationInput, PearlTraceInput, RawTraceInput};
-use crate::outputs::{PearlTraceOutput, TNTResultOutput};
+use crate::inputs::{
+    CalculationInput, ChunkPathInput, DispenserInput, GroupCalculationInput,
+    LandingDistributionInput, PearlTraceInput, RawTraceInput, SensitivityInput, ThrowInput,
+};
+use crate::outputs::{
+    ChunkPathOutput, DispensedPearlOutput, GroupTNTResultOutput, LandingDistributionOutput,
+    PearlTraceOutput, SensitivityOutput, TNTResultOutput, ThrowResultOutput,
+};
 
 use pearl_calculator_core::calculation::calculation::{
+    calculate_chunk_path as core_calculate_chunk_path,
+    calculate_group_tnt_amount as core_calculate_group_tnt_amount,
+    calculate_landing_distribution as core_calculate_landing_distribution,
     calculate_pearl_trace as core_calculate_pearl_trace,
     calculate_raw_trace as core_calculate_raw_trace,
-    calculate_tnt_amount as core_calculate_tnt_amount,
+    calculate_sensitivity as core_calculate_sensitivity, calculate_throw as core_calculate_throw,
+    calculate_tnt_amount as core_calculate_tnt_amount, dispense_pearl, sample_dispensed_pearls,
 };
+use pearl_calculator_core::calculation::inputs::TNT;
+use pearl_calculator_core::physics::world::dimension::Dimension;
 use pearl_calculator_core::physics::world::space::Space3D;
 
 pub fn calculate_tnt_amount(input: CalculationInput) -> Result<Vec<TNTResultOutput>, String> {
     let version = input.get_version()?;
     let cannon = input.get_cannon()?;
     let destination = input.get_destination();
+    let destination_dimension = input.get_destination_dimension()?;
+    let world = input.get_world()?;
+
+    if cannon.dimension != destination_dimension
+        && (cannon.dimension == Dimension::End || destination_dimension == Dimension::End)
+    {
+        return Err("The End has no coordinate mapping to other dimensions".to_string());
+    }
 
     let results = core_calculate_tnt_amount(
         &cannon,
         destination,
+        destination_dimension,
         input.max_tnt,
         input.max_vertical_tnt,
         input.max_ticks,
         input.max_distance,
+        &world,
         version,
     );
 
     Ok(results.into_iter().map(Into::into).collect())
 }
 
+pub fn calculate_group_tnt_amount(
+    input: GroupCalculationInput,
+) -> Result<Vec<GroupTNTResultOutput>, String> {
+    let version = input.get_version()?;
+    let cannon = input.get_cannon()?;
+    let destination = input.get_destination();
+    let world = input.get_world()?;
+
+    let results = core_calculate_group_tnt_amount(
+        &cannon,
+        destination,
+        input.max_ticks,
+        input.max_distance,
+        &world,
+        version,
+    );
+
+    Ok(results
+        .into_iter()
+        .map(|r| GroupTNTResultOutput::from_core(r, &cannon.groups))
+        .collect())
+}
+
 pub fn calculate_pearl_trace(input: PearlTraceInput) -> Result<PearlTraceOutput, String> {
     let version = input.get_version()?;
     let cannon = input.get_cannon()?;
     let flight_direction = input.get_flight_direction()?;
+    let kind = input.get_entity_kind()?;
+    let world = input.get_world()?;
 
     let result = core_calculate_pearl_trace(
         &cannon,
@@ -37,8 +84,9 @@ pub fn calculate_pearl_trace(input: PearlTraceInput) -> Result<PearlTraceOutput,
         input.blue_tnt,
         input.vertical_tnt_amount.unwrap_or(0),
         flight_direction,
+        kind,
         10000,
-        &[],
+        &world,
         version,
     )
     .ok_or_else(|| "Pearl trace calculation failed".to_string())?;
@@ -49,8 +97,50 @@ pub fn calculate_pearl_trace(input: PearlTraceInput) -> Result<PearlTraceOutput,
     ))
 }
 
+pub fn calculate_landing_distribution(
+    input: LandingDistributionInput,
+) -> Result<LandingDistributionOutput, String> {
+    let trace = &input.trace;
+    let version = trace.get_version()?;
+    let cannon = trace.get_cannon()?;
+    let flight_direction = trace.get_flight_direction()?;
+    let world = trace.get_world()?;
+    let params = input.get_params()?;
+
+    let distribution = core_calculate_landing_distribution(
+        &cannon,
+        trace.red_tnt,
+        trace.blue_tnt,
+        trace.vertical_tnt_amount.unwrap_or(0),
+        flight_direction,
+        trace.get_destination(),
+        &params,
+        10000,
+        &world,
+        version,
+    )
+    .ok_or_else(|| "Landing distribution calculation failed".to_string())?;
+
+    Ok(distribution.into())
+}
+
+pub fn calculate_sensitivity(input: SensitivityInput) -> Result<SensitivityOutput, String> {
+    let trace = &input.trace;
+    let version = trace.get_version()?;
+    let cannon = trace.get_cannon()?;
+    let result = input.get_result()?;
+    let world = trace.get_world()?;
+
+    let sensitivity = core_calculate_sensitivity(&cannon, &result, &world, version)
+        .ok_or_else(|| "Sensitivity calculation failed".to_string())?;
+
+    Ok(sensitivity.into())
+}
+
 pub fn calculate_raw_trace(input: RawTraceInput) -> Result<PearlTraceOutput, String> {
     let version = input.get_version()?;
+    let kind = input.get_entity_kind()?;
+    let world = input.get_world()?;
 
     let pearl_pos = Space3D::new(input.pearl_x, input.pearl_y, input.pearl_z);
     let pearl_motion = Space3D::new(
@@ -59,15 +149,65 @@ pub fn calculate_raw_trace(input: RawTraceInput) -> Result<PearlTraceOutput, Str
         input.pearl_motion_z,
     );
 
-    let tnt_charges: Vec<(Space3D, u32)> = input
+    let tnt_charges: Vec<(TNT, u32)> = input
         .tnt_groups
         .iter()
-        .map(|g| (Space3D::new(g.x, g.y, g.z), g.amount))
-        .collect();
+        .map(|g| {
+            let tnt = TNT {
+                position: Space3D::new(g.x, g.y, g.z),
+                fuse: g.fuse.unwrap_or(0),
+                source: g.get_source()?,
+            };
+            Ok((tnt, g.amount))
+        })
+        .collect::<Result<_, String>>()?;
 
-    let result =
-        core_calculate_raw_trace(pearl_pos, pearl_motion, tnt_charges, 10000, &[], version)
-            .ok_or_else(|| "Raw trace calculation failed".to_string())?;
+    let result = core_calculate_raw_trace(
+        pearl_pos,
+        pearl_motion,
+        kind,
+        tnt_charges,
+        10000,
+        &world,
+        version,
+    )
+    .ok_or_else(|| "Raw trace calculation failed".to_string())?;
 
     Ok(PearlTraceOutput::from_core(result, None))
 }
+
+pub fn calculate_chunk_path(input: ChunkPathInput) -> Result<ChunkPathOutput, String> {
+    if input.trace.is_empty() {
+        return Err("Trace is empty".to_string());
+    }
+
+    let report = core_calculate_chunk_path(&input.get_trace(), input.simulation_distance);
+    Ok(report.into())
+}
+
+pub fn calculate_throw(input: ThrowInput) -> Result<Vec<ThrowResultOutput>, String> {
+    let version = input.get_version()?;
+    let world = input.get_world()?;
+
+    let results = core_calculate_throw(
+        &input.get_thrower(),
+        input.get_destination(),
+        input.max_ticks,
+        &world,
+        version,
+    );
+
+    Ok(results.into_iter().map(Into::into).collect())
+}
+
+pub fn calculate_dispensed_pearl(input: DispenserInput) -> Result<DispensedPearlOutput, String> {
+    let dispenser = input.get_dispenser()?;
+
+    let samples =
+        sample_dispensed_pearls(&dispenser, input.get_samples()?, input.seed.unwrap_or(0));
+
+    Ok(DispensedPearlOutput {
+        mean: dispense_pearl(&dispenser).into(),
+        samples: samples.into_iter().map(Into::into).collect(),
+    })
+}
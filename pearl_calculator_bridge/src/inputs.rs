@@ -1,6 +1,16 @@
-use pearl_calculator_core::calculation::inputs::{Cannon, Pearl};
+use pearl_calculator_core::calculation::inputs::{
+    Cannon, Dispenser, GroupCannon, Pearl, SpreadParams, Thrower, TntGroup,
+};
+use pearl_calculator_core::calculation::results::TNTResult;
+use pearl_calculator_core::physics::constants::constants::{PLAYER_EYE_HEIGHT, TNT_DEFAULT_FUSE};
 
+use pearl_calculator_core::physics::entities::entity_kind::EntityKind;
 use pearl_calculator_core::physics::entities::movement::PearlVersion;
+use pearl_calculator_core::physics::explosion::source::ExplosionSource;
+use pearl_calculator_core::physics::world::block_face::BlockFace;
+use pearl_calculator_core::physics::world::chunk::{ChunkLoading, ChunkPos};
+use pearl_calculator_core::physics::world::collision_world::CollisionWorld;
+use pearl_calculator_core::physics::world::dimension::Dimension;
 use pearl_calculator_core::physics::world::direction::Direction;
 use pearl_calculator_core::physics::world::layout_direction::LayoutDirection;
 use pearl_calculator_core::physics::world::space::Space3D;
@@ -24,6 +34,10 @@ pub struct CalculationInput {
     pub north_east_tnt: Space3DInput,
     pub south_west_tnt: Space3DInput,
     pub south_east_tnt: Space3DInput,
+    pub north_tnt: Option<Space3DInput>,
+    pub south_tnt: Option<Space3DInput>,
+    pub west_tnt: Option<Space3DInput>,
+    pub east_tnt: Option<Space3DInput>,
 
     pub default_red_direction: String,
     pub default_blue_direction: String,
@@ -31,6 +45,8 @@ pub struct CalculationInput {
     pub destination_x: f64,
     pub destination_y: Option<f64>,
     pub destination_z: f64,
+    pub dimension: Option<String>,
+    pub destination_dimension: Option<String>,
 
     pub max_tnt: u32,
     pub max_ticks: u32,
@@ -40,6 +56,9 @@ pub struct CalculationInput {
     pub vertical_tnt: Option<Space3DInput>,
     pub max_vertical_tnt: Option<u32>,
     pub mode: Option<String>,
+
+    pub red_tnt_override: Option<Space3DInput>,
+    pub blue_tnt_override: Option<Space3DInput>,
 }
 
 impl CalculationInput {
@@ -62,10 +81,17 @@ impl CalculationInput {
             &self.north_east_tnt,
             &self.south_west_tnt,
             &self.south_east_tnt,
+            self.north_tnt,
+            self.south_tnt,
+            self.west_tnt,
+            self.east_tnt,
             &self.default_red_direction,
             &self.default_blue_direction,
             self.vertical_tnt,
             self.mode.clone(),
+            self.red_tnt_override,
+            self.blue_tnt_override,
+            self.dimension.as_deref(),
         )
     }
 
@@ -76,6 +102,20 @@ impl CalculationInput {
             self.destination_z,
         )
     }
+
+    /// Applies the cannon's dimension, so its height limits and ceiling are in the way.
+    pub fn get_world(&self) -> Result<CollisionWorld, String> {
+        build_world(parse_dimension(self.dimension.as_deref())?, None)
+    }
+
+    /// Defaults to the cannon's dimension.
+    pub fn get_destination_dimension(&self) -> Result<Dimension, String> {
+        parse_dimension(
+            self.destination_dimension
+                .as_deref()
+                .or(self.dimension.as_deref()),
+        )
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,15 +137,24 @@ pub struct PearlTraceInput {
     pub north_east_tnt: Space3DInput,
     pub south_west_tnt: Space3DInput,
     pub south_east_tnt: Space3DInput,
+    pub north_tnt: Option<Space3DInput>,
+    pub south_tnt: Option<Space3DInput>,
+    pub west_tnt: Option<Space3DInput>,
+    pub east_tnt: Option<Space3DInput>,
     pub default_red_direction: String,
     pub default_blue_direction: String,
     pub destination_x: f64,
     pub destination_y: Option<f64>,
     pub destination_z: f64,
+    pub dimension: Option<String>,
     pub direction: Option<String>,
     pub version: String,
     pub vertical_tnt: Option<Space3DInput>,
     pub mode: Option<String>,
+    pub red_tnt_override: Option<Space3DInput>,
+    pub blue_tnt_override: Option<Space3DInput>,
+    pub chunk_loading: Option<ChunkLoadingInput>,
+    pub entity_kind: Option<String>,
 }
 
 impl PearlTraceInput {
@@ -113,6 +162,17 @@ impl PearlTraceInput {
         parse_version(&self.version)
     }
 
+    pub fn get_entity_kind(&self) -> Result<EntityKind, String> {
+        parse_entity_kind(self.entity_kind.as_deref())
+    }
+
+    pub fn get_world(&self) -> Result<CollisionWorld, String> {
+        build_world(
+            parse_dimension(self.dimension.as_deref())?,
+            self.chunk_loading.as_ref(),
+        )
+    }
+
     pub fn get_cannon(&self) -> Result<Cannon, String> {
         build_cannon(
             self.pearl_x,
@@ -128,10 +188,17 @@ impl PearlTraceInput {
             &self.north_east_tnt,
             &self.south_west_tnt,
             &self.south_east_tnt,
+            self.north_tnt,
+            self.south_tnt,
+            self.west_tnt,
+            self.east_tnt,
             &self.default_red_direction,
             &self.default_blue_direction,
             self.vertical_tnt,
             self.mode.clone(),
+            self.red_tnt_override,
+            self.blue_tnt_override,
+            self.dimension.as_deref(),
         )
     }
 
@@ -151,6 +218,86 @@ impl PearlTraceInput {
             Ok(direction_from_layout(default_red))
         }
     }
+
+    pub fn get_destination(&self) -> Space3D {
+        Space3D::new(
+            self.destination_x,
+            self.destination_y.unwrap_or(0.0),
+            self.destination_z,
+        )
+    }
+}
+
+/// Most shots a single landing distribution may replay.
+pub const MAX_DISTRIBUTION_SAMPLES: u32 = 100_000;
+
+/// A pearl trace replayed with sampled TNT randomness.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LandingDistributionInput {
+    #[serde(flatten)]
+    pub trace: PearlTraceInput,
+    pub samples: Option<u32>,
+    pub seed: Option<i64>,
+    pub fuse: Option<u32>,
+    pub spawn_spread: Option<f64>,
+    pub hit_radius: Option<f64>,
+}
+
+impl LandingDistributionInput {
+    /// 1000 samples from seed 0 unless given, so the same input always gives the same result.
+    /// At most `MAX_DISTRIBUTION_SAMPLES` shots are replayed.
+    pub fn get_params(&self) -> Result<SpreadParams, String> {
+        let samples = self.samples.unwrap_or(1000);
+        if samples > MAX_DISTRIBUTION_SAMPLES {
+            return Err(format!(
+                "At most {} shots can be sampled",
+                MAX_DISTRIBUTION_SAMPLES
+            ));
+        }
+
+        Ok(SpreadParams {
+            samples,
+            seed: self.seed.unwrap_or(0),
+            fuse: self.fuse.unwrap_or(TNT_DEFAULT_FUSE),
+            spawn_spread: self.spawn_spread.unwrap_or(0.0),
+            hit_radius: self.hit_radius.unwrap_or(0.5),
+        })
+    }
+}
+
+/// A pearl trace and the tick of the `TNTResult` it came from, to measure how sensitive
+/// its landing point is.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SensitivityInput {
+    #[serde(flatten)]
+    pub trace: PearlTraceInput,
+    pub tick: u32,
+}
+
+impl SensitivityInput {
+    /// The shot being measured. Sensitivity only reads its TNT counts, direction and tick,
+    /// so the landing fields are left empty.
+    pub fn get_result(&self) -> Result<TNTResult, String> {
+        let trace = &self.trace;
+        let vertical = trace.vertical_tnt_amount.unwrap_or(0);
+        Ok(TNTResult {
+            distance: 0.0,
+            tick: self.tick,
+            blue: trace.blue_tnt,
+            red: trace.red_tnt,
+            vertical,
+            yaw: 0.0,
+            pitch: 0.0,
+            total: trace.red_tnt + trace.blue_tnt + vertical,
+            pearl_end_pos: Space3D::default(),
+            pearl_end_motion: Space3D::default(),
+            direction: trace.get_flight_direction()?,
+            conversion: None,
+            arrival: None,
+        })
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Copy)]
@@ -173,6 +320,44 @@ pub struct TntGroupInput {
     pub y: f64,
     pub z: f64,
     pub amount: u32,
+    /// Ticks until the group explodes. The boost lands in tick `fuse`, so the first trace
+    /// point it moves is `pearl_trace[fuse]`; without a fuse it is part of the launch motion.
+    pub fuse: Option<u32>,
+    pub source: Option<ExplosionSourceInput>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplosionSourceInput {
+    pub kind: String,
+    pub speed: Option<f64>,
+    pub charged: Option<bool>,
+}
+
+impl ExplosionSourceInput {
+    pub fn to_core(&self) -> Result<ExplosionSource, String> {
+        match self.kind.as_str() {
+            "Tnt" => Ok(ExplosionSource::Tnt),
+            "EndCrystal" => Ok(ExplosionSource::EndCrystal),
+            "Bed" => Ok(ExplosionSource::Bed),
+            "RespawnAnchor" => Ok(ExplosionSource::RespawnAnchor),
+            "TntMinecart" => Ok(ExplosionSource::TntMinecart {
+                speed: self.speed.unwrap_or(0.0),
+            }),
+            "Creeper" => Ok(ExplosionSource::Creeper {
+                charged: self.charged.unwrap_or(false),
+            }),
+            "WindCharge" => Ok(ExplosionSource::WindCharge),
+            "BreezeWindCharge" => Ok(ExplosionSource::BreezeWindCharge),
+            _ => Err("Invalid explosion source".to_string()),
+        }
+    }
+}
+
+impl TntGroupInput {
+    pub fn get_source(&self) -> Result<ExplosionSource, String> {
+        parse_explosion_source(self.source.as_ref())
+    }
 }
 
 #[derive(serde::Deserialize)]
@@ -185,13 +370,263 @@ pub struct RawTraceInput {
     pub pearl_motion_y: f64,
     pub pearl_motion_z: f64,
     pub tnt_groups: Vec<TntGroupInput>,
+    pub dimension: Option<String>,
     pub version: String,
+    pub chunk_loading: Option<ChunkLoadingInput>,
+    pub entity_kind: Option<String>,
 }
 
 impl RawTraceInput {
     pub fn get_version(&self) -> Result<PearlVersion, String> {
         parse_version(&self.version)
     }
+
+    pub fn get_entity_kind(&self) -> Result<EntityKind, String> {
+        parse_entity_kind(self.entity_kind.as_deref())
+    }
+
+    pub fn get_world(&self) -> Result<CollisionWorld, String> {
+        build_world(
+            parse_dimension(self.dimension.as_deref())?,
+            self.chunk_loading.as_ref(),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkPathInput {
+    pub trace: Vec<Space3DInput>,
+    pub simulation_distance: u32,
+}
+
+impl ChunkPathInput {
+    pub fn get_trace(&self) -> Vec<Space3D> {
+        self.trace.iter().map(|&point| point.into()).collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThrowInput {
+    pub player_x: f64,
+    pub player_y: f64,
+    pub player_z: f64,
+    /// Defaults to a standing player's.
+    pub eye_height: Option<f64>,
+    pub player_motion: Option<Space3DInput>,
+    pub on_ground: Option<bool>,
+    pub destination_x: f64,
+    pub destination_y: f64,
+    pub destination_z: f64,
+    pub dimension: Option<String>,
+    pub max_ticks: u32,
+    pub version: String,
+    pub chunk_loading: Option<ChunkLoadingInput>,
+}
+
+impl ThrowInput {
+    pub fn get_version(&self) -> Result<PearlVersion, String> {
+        parse_version(&self.version)
+    }
+
+    pub fn get_world(&self) -> Result<CollisionWorld, String> {
+        build_world(
+            parse_dimension(self.dimension.as_deref())?,
+            self.chunk_loading.as_ref(),
+        )
+    }
+
+    pub fn get_thrower(&self) -> Thrower {
+        Thrower {
+            eye_position: Space3D::new(
+                self.player_x,
+                self.player_y + self.eye_height.unwrap_or(PLAYER_EYE_HEIGHT),
+                self.player_z,
+            ),
+            motion: self.player_motion.map(Into::into).unwrap_or_default(),
+            on_ground: self.on_ground.unwrap_or(true),
+        }
+    }
+
+    pub fn get_destination(&self) -> Space3D {
+        Space3D::new(self.destination_x, self.destination_y, self.destination_z)
+    }
+}
+
+/// Most spread samples a single dispenser request may ask for.
+pub const MAX_DISPENSED_SAMPLES: u32 = 100_000;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DispenserInput {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub facing: String,
+    pub samples: Option<u32>,
+    pub seed: Option<i64>,
+}
+
+impl DispenserInput {
+    pub fn get_dispenser(&self) -> Result<Dispenser, String> {
+        Ok(Dispenser {
+            block: (self.x, self.y, self.z),
+            facing: parse_block_face(&self.facing)?,
+        })
+    }
+
+    /// No samples unless given, and at most `MAX_DISPENSED_SAMPLES`.
+    pub fn get_samples(&self) -> Result<usize, String> {
+        let samples = self.samples.unwrap_or(0);
+        if samples > MAX_DISPENSED_SAMPLES {
+            return Err(format!(
+                "At most {} dispenser samples can be drawn",
+                MAX_DISPENSED_SAMPLES
+            ));
+        }
+        Ok(samples as usize)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct ChunkInput {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl From<ChunkInput> for ChunkPos {
+    fn from(input: ChunkInput) -> Self {
+        ChunkPos::new(input.x, input.z)
+    }
+}
+
+/// Either the exact set of entity-ticking chunks, or loader chunks plus the server's
+/// simulation distance.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkLoadingInput {
+    pub loaded_chunks: Option<Vec<ChunkInput>>,
+    pub loaders: Option<Vec<ChunkInput>>,
+    pub simulation_distance: Option<u32>,
+}
+
+impl ChunkLoadingInput {
+    pub fn to_core(&self) -> Result<ChunkLoading, String> {
+        match (&self.loaded_chunks, &self.loaders) {
+            (Some(chunks), None) => Ok(ChunkLoading::Chunks(
+                chunks.iter().map(|&chunk| chunk.into()).collect(),
+            )),
+            (None, Some(loaders)) => {
+                let simulation_distance = self
+                    .simulation_distance
+                    .ok_or_else(|| "Chunk loaders need a simulation distance".to_string())?;
+                Ok(ChunkLoading::from_simulation_distance(
+                    loaders.iter().map(|&chunk| chunk.into()).collect(),
+                    simulation_distance,
+                ))
+            }
+            _ => Err("Specify either loaded chunks or chunk loaders".to_string()),
+        }
+    }
+}
+
+fn build_world(
+    dimension: Dimension,
+    chunk_loading: Option<&ChunkLoadingInput>,
+) -> Result<CollisionWorld, String> {
+    let world = CollisionWorld::default().with_dimension(dimension.settings());
+    match chunk_loading {
+        Some(chunk_loading) => Ok(world.with_chunk_loading(chunk_loading.to_core()?)),
+        None => Ok(world),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CannonGroupInput {
+    pub name: String,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub max_count: Option<u32>,
+    pub vertical: Option<bool>,
+    pub source: Option<ExplosionSourceInput>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupCalculationInput {
+    pub pearl_x: f64,
+    pub pearl_y: f64,
+    pub pearl_z: f64,
+    pub pearl_motion_x: f64,
+    pub pearl_motion_y: f64,
+    pub pearl_motion_z: f64,
+    pub offset_x: f64,
+    pub offset_z: f64,
+    pub cannon_y: f64,
+    pub groups: Vec<CannonGroupInput>,
+    pub destination_x: f64,
+    pub destination_y: Option<f64>,
+    pub destination_z: f64,
+    pub max_ticks: u32,
+    pub max_distance: f64,
+    pub version: String,
+    pub dimension: Option<String>,
+}
+
+impl GroupCalculationInput {
+    pub fn get_version(&self) -> Result<PearlVersion, String> {
+        parse_version(&self.version)
+    }
+
+    /// Applies the cannon's dimension, as [`CalculationInput::get_world`] does.
+    pub fn get_world(&self) -> Result<CollisionWorld, String> {
+        build_world(parse_dimension(self.dimension.as_deref())?, None)
+    }
+
+    pub fn get_cannon(&self) -> Result<GroupCannon, String> {
+        if self.groups.is_empty() {
+            return Err("Cannon has no TNT groups".to_string());
+        }
+
+        let y_offset = self.cannon_y - self.pearl_y.floor();
+        let groups = self
+            .groups
+            .iter()
+            .map(|g| {
+                Ok(TntGroup {
+                    name: g.name.clone(),
+                    position: Space3D::new(g.x, g.y + y_offset, g.z),
+                    max_count: g.max_count.unwrap_or(0),
+                    vertical: g.vertical.unwrap_or(false),
+                    source: parse_explosion_source(g.source.as_ref())?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(GroupCannon {
+            pearl: Pearl {
+                position: Space3D::new(self.pearl_x, self.pearl_y + y_offset, self.pearl_z),
+                motion: Space3D::new(
+                    self.pearl_motion_x,
+                    self.pearl_motion_y,
+                    self.pearl_motion_z,
+                ),
+                offset: Space3D::new(self.offset_x, 0.0, self.offset_z),
+            },
+            groups,
+        })
+    }
+
+    pub fn get_destination(&self) -> Space3D {
+        Space3D::new(
+            self.destination_x,
+            self.destination_y.unwrap_or(0.0),
+            self.destination_z,
+        )
+    }
 }
 
 fn parse_version(s: &str) -> Result<PearlVersion, String> {
@@ -203,12 +638,53 @@ fn parse_version(s: &str) -> Result<PearlVersion, String> {
     }
 }
 
+fn parse_dimension(s: Option<&str>) -> Result<Dimension, String> {
+    match s {
+        None | Some("Overworld") => Ok(Dimension::Overworld),
+        Some("Nether") => Ok(Dimension::Nether),
+        Some("End") => Ok(Dimension::End),
+        _ => Err("Invalid dimension".to_string()),
+    }
+}
+
+fn parse_block_face(s: &str) -> Result<BlockFace, String> {
+    match s {
+        "Down" => Ok(BlockFace::Down),
+        "Up" => Ok(BlockFace::Up),
+        "North" => Ok(BlockFace::North),
+        "South" => Ok(BlockFace::South),
+        "West" => Ok(BlockFace::West),
+        "East" => Ok(BlockFace::East),
+        _ => Err("Invalid facing".to_string()),
+    }
+}
+
+fn parse_entity_kind(s: Option<&str>) -> Result<EntityKind, String> {
+    match s {
+        None | Some("Pearl") => Ok(EntityKind::Pearl),
+        Some("Tnt") => Ok(EntityKind::Tnt),
+        Some("FallingBlock") => Ok(EntityKind::FallingBlock),
+        Some("Player") => Ok(EntityKind::Player),
+        _ => Err("Invalid entity kind".to_string()),
+    }
+}
+
+fn parse_explosion_source(
+    source: Option<&ExplosionSourceInput>,
+) -> Result<ExplosionSource, String> {
+    source.map_or(Ok(ExplosionSource::Tnt), ExplosionSourceInput::to_core)
+}
+
 fn parse_layout_direction(s: &str) -> Option<LayoutDirection> {
     match s {
         "NorthWest" => Some(LayoutDirection::NorthWest),
         "NorthEast" => Some(LayoutDirection::NorthEast),
         "SouthWest" => Some(LayoutDirection::SouthWest),
         "SouthEast" => Some(LayoutDirection::SouthEast),
+        "North" => Some(LayoutDirection::North),
+        "South" => Some(LayoutDirection::South),
+        "West" => Some(LayoutDirection::West),
+        "East" => Some(LayoutDirection::East),
         _ => None,
     }
 }
@@ -219,7 +695,10 @@ fn direction_from_layout(layout: LayoutDirection) -> Direction {
         LayoutDirection::NorthEast => Direction::East,
         LayoutDirection::SouthWest => Direction::West,
         LayoutDirection::SouthEast => Direction::South,
-        _ => unreachable!(),
+        LayoutDirection::North => Direction::North,
+        LayoutDirection::South => Direction::South,
+        LayoutDirection::West => Direction::West,
+        LayoutDirection::East => Direction::East,
     }
 }
 
@@ -237,14 +716,29 @@ fn build_cannon(
     ne: &Space3DInput,
     sw: &Space3DInput,
     se: &Space3DInput,
+    north: Option<Space3DInput>,
+    south: Option<Space3DInput>,
+    west: Option<Space3DInput>,
+    east: Option<Space3DInput>,
     red_dir: &str,
     blue_dir: &str,
     vert: Option<Space3DInput>,
     mode_str: Option<String>,
+    red_override: Option<Space3DInput>,
+    blue_override: Option<Space3DInput>,
+    dimension: Option<&str>,
 ) -> Result<Cannon, String> {
     let y_offset = cy - py.floor();
     let default_red_direction = parse_layout_direction(red_dir);
     let default_blue_direction = parse_layout_direction(blue_dir);
+    let red_duper = default_red_direction.unwrap_or(LayoutDirection::NorthWest);
+    let blue_duper = default_blue_direction.unwrap_or(LayoutDirection::NorthEast);
+    if !red_duper.pairs_with(blue_duper) {
+        return Err(
+            "Red and blue dupers must both be corners or sit on sides of different axes"
+                .to_string(),
+        );
+    }
 
     let mode = match mode_str.as_deref() {
         Some("Accumulation") => CannonMode::Accumulation,
@@ -252,10 +746,16 @@ fn build_cannon(
     };
 
     let vertical_tnt = vert.map(|v| Space3D::new(v.x, v.y + y_offset, v.z));
+    let side_pos = |side: Option<Space3DInput>| {
+        side.map_or(Space3D::default(), |v| {
+            Space3D::new(v.x, v.y + y_offset, v.z)
+        })
+    };
     let nw_pos = Space3D::new(nw.x, nw.y + y_offset, nw.z);
     let ne_pos = Space3D::new(ne.x, ne.y + y_offset, ne.z);
 
-    let (red_override, blue_override) = (None, None);
+    let red_override = red_override.map(|v| Space3D::new(v.x, v.y + y_offset, v.z));
+    let blue_override = blue_override.map(|v| Space3D::new(v.x, v.y + y_offset, v.z));
 
     Ok(Cannon {
         pearl: Pearl {
@@ -271,7 +771,12 @@ fn build_cannon(
         north_east_tnt: ne_pos,
         south_west_tnt: Space3D::new(sw.x, sw.y + y_offset, sw.z),
         south_east_tnt: Space3D::new(se.x, se.y + y_offset, se.z),
+        north_tnt: side_pos(north),
+        south_tnt: side_pos(south),
+        west_tnt: side_pos(west),
+        east_tnt: side_pos(east),
         default_red_duper: default_red_direction,
         default_blue_duper: default_blue_direction,
+        dimension: parse_dimension(dimension)?,
     })
 }
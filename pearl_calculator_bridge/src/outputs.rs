@@ -1,4 +1,8 @@
-use pearl_calculator_core::calculation::results::{CalculationResult, TNTResult};
+use pearl_calculator_core::calculation::inputs::{Pearl, TntGroup};
+use pearl_calculator_core::calculation::results::{
+    Arrival, CalculationResult, ChunkPathReport, DimensionConversion, GroupTNTResult,
+    LandingDistribution, Sensitivity, TNTResult, ThrowResult, TntSensitivity,
+};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
@@ -14,6 +18,37 @@ pub struct TNTResultOutput {
     pub pearl_end_pos: Space3DOutput,
     pub pearl_end_motion: Space3DOutput,
     pub direction: String,
+    pub conversion: Option<DimensionConversionOutput>,
+    pub arrival: Option<ArrivalOutput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DimensionConversionOutput {
+    pub dimension: String,
+    pub pearl_end_pos: Space3DOutput,
+    pub warning: Option<String>,
+}
+
+impl From<DimensionConversion> for DimensionConversionOutput {
+    fn from(c: DimensionConversion) -> Self {
+        DimensionConversionOutput {
+            dimension: format!("{:?}", c.dimension),
+            pearl_end_pos: Space3DOutput {
+                x: c.pearl_end_pos.x,
+                y: c.pearl_end_pos.y,
+                z: c.pearl_end_pos.z,
+            },
+            warning: c.portal_rounding.map(|p| {
+                format!(
+                    "Pearl lands in nether block ({}, {}) but the destination is in ({}, {}); a portal at the landing spot links elsewhere",
+                    p.landing_block.0,
+                    p.landing_block.1,
+                    p.destination_block.0,
+                    p.destination_block.1
+                )
+            }),
+        }
+    }
 }
 
 impl From<TNTResult> for TNTResultOutput {
@@ -38,6 +73,240 @@ impl From<TNTResult> for TNTResultOutput {
                 z: r.pearl_end_motion.z,
             },
             direction: format!("{:?}", r.direction),
+            conversion: r.conversion.map(Into::into),
+            arrival: r.arrival.map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupCountOutput {
+    pub name: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupTNTResultOutput {
+    pub distance: f64,
+    pub tick: u32,
+    pub counts: Vec<GroupCountOutput>,
+    pub yaw: f64,
+    pub pitch: f64,
+    pub total: u32,
+    pub pearl_end_pos: Space3DOutput,
+    pub pearl_end_motion: Space3DOutput,
+    pub direction: String,
+}
+
+impl GroupTNTResultOutput {
+    pub fn from_core(result: GroupTNTResult, groups: &[TntGroup]) -> Self {
+        GroupTNTResultOutput {
+            distance: result.distance,
+            tick: result.tick,
+            counts: groups
+                .iter()
+                .zip(&result.counts)
+                .map(|(group, &count)| GroupCountOutput {
+                    name: group.name.clone(),
+                    count,
+                })
+                .collect(),
+            yaw: result.yaw,
+            pitch: result.pitch,
+            total: result.total,
+            pearl_end_pos: Space3DOutput {
+                x: result.pearl_end_pos.x,
+                y: result.pearl_end_pos.y,
+                z: result.pearl_end_pos.z,
+            },
+            pearl_end_motion: Space3DOutput {
+                x: result.pearl_end_motion.x,
+                y: result.pearl_end_motion.y,
+                z: result.pearl_end_motion.z,
+            },
+            direction: format!("{:?}", result.direction),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ThrowResultOutput {
+    pub arc: String,
+    pub yaw: f64,
+    pub pitch: f64,
+    pub pearl_start_pos: Space3DOutput,
+    pub pearl_start_motion: Space3DOutput,
+    pub tick: u32,
+    pub pearl_end_pos: Space3DOutput,
+    pub distance: f64,
+    pub status: String,
+}
+
+impl From<ThrowResult> for ThrowResultOutput {
+    fn from(r: ThrowResult) -> Self {
+        ThrowResultOutput {
+            arc: format!("{:?}", r.arc),
+            yaw: r.yaw,
+            pitch: r.pitch,
+            pearl_start_pos: Space3DOutput {
+                x: r.pearl_start_pos.x,
+                y: r.pearl_start_pos.y,
+                z: r.pearl_start_pos.z,
+            },
+            pearl_start_motion: Space3DOutput {
+                x: r.pearl_start_motion.x,
+                y: r.pearl_start_motion.y,
+                z: r.pearl_start_motion.z,
+            },
+            tick: r.tick,
+            pearl_end_pos: Space3DOutput {
+                x: r.pearl_end_pos.x,
+                y: r.pearl_end_pos.y,
+                z: r.pearl_end_pos.z,
+            },
+            distance: r.distance,
+            status: format!("{:?}", r.status),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LandingDistributionOutput {
+    pub tick: u32,
+    pub landings: Vec<Space3DOutput>,
+    pub mean: Space3DOutput,
+    pub covariance: [[f64; 2]; 2],
+    pub ellipse: EllipseOutput,
+    pub hit_probability: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EllipseOutput {
+    pub semi_major: f64,
+    pub semi_minor: f64,
+    pub angle: f64,
+}
+
+impl From<LandingDistribution> for LandingDistributionOutput {
+    fn from(d: LandingDistribution) -> Self {
+        LandingDistributionOutput {
+            tick: d.tick,
+            landings: d
+                .landings
+                .iter()
+                .map(|landing| Space3DOutput {
+                    x: landing.x,
+                    y: landing.y,
+                    z: landing.z,
+                })
+                .collect(),
+            mean: Space3DOutput {
+                x: d.mean.x,
+                y: d.mean.y,
+                z: d.mean.z,
+            },
+            covariance: d.covariance,
+            ellipse: EllipseOutput {
+                semi_major: d.ellipse.semi_major,
+                semi_minor: d.ellipse.semi_minor,
+                angle: d.ellipse.angle,
+            },
+            hit_probability: d.hit_probability,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SensitivityOutput {
+    pub tick: u32,
+    pub landing: Space3DOutput,
+    pub red: TntSensitivityOutput,
+    pub blue: TntSensitivityOutput,
+    pub vertical: Option<TntSensitivityOutput>,
+    pub position: [Space3DOutput; 3],
+    pub motion: [Space3DOutput; 3],
+}
+
+#[derive(Debug, Serialize)]
+pub struct TntSensitivityOutput {
+    pub more: Space3DOutput,
+    pub fewer: Option<Space3DOutput>,
+}
+
+impl From<TntSensitivity> for TntSensitivityOutput {
+    fn from(t: TntSensitivity) -> Self {
+        TntSensitivityOutput {
+            more: Space3DOutput {
+                x: t.more.x,
+                y: t.more.y,
+                z: t.more.z,
+            },
+            fewer: t.fewer.map(|fewer| Space3DOutput {
+                x: fewer.x,
+                y: fewer.y,
+                z: fewer.z,
+            }),
+        }
+    }
+}
+
+impl From<Sensitivity> for SensitivityOutput {
+    fn from(s: Sensitivity) -> Self {
+        SensitivityOutput {
+            tick: s.tick,
+            landing: Space3DOutput {
+                x: s.landing.x,
+                y: s.landing.y,
+                z: s.landing.z,
+            },
+            red: s.red.into(),
+            blue: s.blue.into(),
+            vertical: s.vertical.map(Into::into),
+            position: s.position.map(|column| Space3DOutput {
+                x: column.x,
+                y: column.y,
+                z: column.z,
+            }),
+            motion: s.motion.map(|column| Space3DOutput {
+                x: column.x,
+                y: column.y,
+                z: column.z,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DispensedPearlOutput {
+    pub mean: PearlStateOutput,
+    pub samples: Vec<PearlStateOutput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PearlStateOutput {
+    pub position: Space3DOutput,
+    pub motion: Space3DOutput,
+    pub offset: Space3DOutput,
+}
+
+impl From<Pearl> for PearlStateOutput {
+    fn from(p: Pearl) -> Self {
+        PearlStateOutput {
+            position: Space3DOutput {
+                x: p.position.x,
+                y: p.position.y,
+                z: p.position.z,
+            },
+            motion: Space3DOutput {
+                x: p.motion.x,
+                y: p.motion.y,
+                z: p.motion.z,
+            },
+            offset: Space3DOutput {
+                x: p.offset.x,
+                y: p.offset.y,
+                z: p.offset.z,
+            },
         }
     }
 }
@@ -52,6 +321,11 @@ pub struct PearlTraceOutput {
     pub final_motion: Space3DOutput,
     pub distance: f64,
     pub closest_approach: Option<ClosestApproachOutput>,
+    pub hit: Option<HitOutput>,
+    pub fluid_ticks: Vec<FluidTickOutput>,
+    pub status: String,
+    pub frozen: Option<FrozenOutput>,
+    pub arrival: Option<ArrivalOutput>,
 }
 
 impl PearlTraceOutput {
@@ -133,6 +407,30 @@ impl PearlTraceOutput {
             },
             distance,
             closest_approach,
+            hit: result.hit.map(|hit| HitOutput {
+                tick: hit.tick,
+                position: Space3DOutput {
+                    x: hit.position.x,
+                    y: hit.position.y,
+                    z: hit.position.z,
+                },
+                face: format!("{:?}", hit.face),
+            }),
+            fluid_ticks: result
+                .fluid_ticks
+                .iter()
+                .map(|fluid_tick| FluidTickOutput {
+                    tick: fluid_tick.tick,
+                    fluid: format!("{:?}", fluid_tick.kind),
+                })
+                .collect(),
+            status: format!("{:?}", result.status),
+            frozen: result.frozen.map(|frozen| FrozenOutput {
+                tick: frozen.tick,
+                chunk_x: frozen.chunk.x,
+                chunk_z: frozen.chunk.z,
+            }),
+            arrival: result.arrival.map(Into::into),
         }
     }
 }
@@ -144,6 +442,149 @@ pub struct ClosestApproachOutput {
     pub distance: f64,
 }
 
+#[derive(Debug, Serialize)]
+pub struct HitOutput {
+    pub tick: u32,
+    pub position: Space3DOutput,
+    pub face: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArrivalOutput {
+    pub teleport_position: Space3DOutput,
+    pub feet_position: Option<Space3DOutput>,
+    pub feet_block: Option<BlockOutput>,
+    pub fall_distance: Option<f64>,
+    pub damage: f64,
+    pub survivable: bool,
+    pub suffocating: bool,
+    pub into_void: bool,
+}
+
+impl From<Arrival> for ArrivalOutput {
+    fn from(a: Arrival) -> Self {
+        ArrivalOutput {
+            teleport_position: Space3DOutput {
+                x: a.teleport_position.x,
+                y: a.teleport_position.y,
+                z: a.teleport_position.z,
+            },
+            feet_position: a.feet_position.map(|feet| Space3DOutput {
+                x: feet.x,
+                y: feet.y,
+                z: feet.z,
+            }),
+            feet_block: a.feet_block.map(|(x, y, z)| BlockOutput { x, y, z }),
+            fall_distance: a.fall_distance,
+            damage: a.damage,
+            survivable: a.survivable,
+            suffocating: a.suffocating,
+            into_void: a.into_void,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockOutput {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrozenOutput {
+    pub tick: u32,
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FluidTickOutput {
+    pub tick: u32,
+    pub fluid: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChunkPathOutput {
+    pub chunks: Vec<ChunkVisitOutput>,
+    pub regions: Vec<RegionOutput>,
+    pub loaders: Vec<ChunkOutput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChunkVisitOutput {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub region_x: i32,
+    pub region_z: i32,
+    pub first_tick: u32,
+    pub last_tick: u32,
+    pub entry: Space3DOutput,
+    pub exit: Space3DOutput,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RegionOutput {
+    pub x: i32,
+    pub z: i32,
+    pub file: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChunkOutput {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+}
+
+impl From<ChunkPathReport> for ChunkPathOutput {
+    fn from(report: ChunkPathReport) -> Self {
+        ChunkPathOutput {
+            chunks: report
+                .chunks
+                .iter()
+                .map(|visit| {
+                    let (region_x, region_z) = visit.chunk.region();
+                    ChunkVisitOutput {
+                        chunk_x: visit.chunk.x,
+                        chunk_z: visit.chunk.z,
+                        region_x,
+                        region_z,
+                        first_tick: visit.first_tick,
+                        last_tick: visit.last_tick,
+                        entry: Space3DOutput {
+                            x: visit.entry.x,
+                            y: visit.entry.y,
+                            z: visit.entry.z,
+                        },
+                        exit: Space3DOutput {
+                            x: visit.exit.x,
+                            y: visit.exit.y,
+                            z: visit.exit.z,
+                        },
+                    }
+                })
+                .collect(),
+            regions: report
+                .regions
+                .iter()
+                .map(|&(x, z)| RegionOutput {
+                    x,
+                    z,
+                    file: format!("r.{x}.{z}.mca"),
+                })
+                .collect(),
+            loaders: report
+                .loaders
+                .iter()
+                .map(|loader| ChunkOutput {
+                    chunk_x: loader.x,
+                    chunk_z: loader.z,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct Space3DOutput {
     #[serde(rename = "X")]
@@ -1,8 +1,15 @@
+mod arrival;
 pub mod calculation;
+mod chunk_path;
+mod dispenser;
 pub mod inputs;
 mod optimizer;
+mod portal;
 pub mod results;
+mod sensitivity;
 pub mod simulation;
 mod solver;
+mod spread;
+mod throw;
 mod trace;
 mod vectors;
@@ -0,0 +1,145 @@
+use crate::calculation::results::Arrival;
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::constants::constants::{
+    FLOAT_PRECISION_EPSILON, PEARL_TELEPORT_DAMAGE, PLAYER_EYE_HEIGHT, PLAYER_HEIGHT,
+    PLAYER_MAX_HEALTH, PLAYER_SAFE_FALL_DISTANCE, PLAYER_WIDTH, SUFFOCATION_CHECK_HEIGHT,
+    SUFFOCATION_CHECK_WIDTH_FACTOR,
+};
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::dimension::DimensionSettings;
+use crate::physics::world::fluid::FluidKind;
+use crate::physics::world::space::Space3D;
+
+/// Where a player teleported to `pearl_position` ends up. The position is in the same frame
+/// as `world`, and the reported positions have `offset` added. Without terrain nothing is
+/// known about the ground below, so the fall is left out.
+pub fn analyze_arrival(
+    pearl_position: Space3D,
+    world: &CollisionWorld,
+    offset: Space3D,
+) -> Arrival {
+    let teleport_box = player_box(pearl_position);
+    let suffocating = is_suffocating(pearl_position, world);
+    let embedded = world
+        .query(&teleport_box)
+        .iter()
+        .any(|aabb| aabb.intersects(&teleport_box));
+
+    let (feet_position, into_void) = if !world.has_terrain() {
+        (None, false)
+    } else if embedded {
+        (Some(pearl_position), false)
+    } else {
+        let (feet_position, into_void) = fall_to_ground(pearl_position, world);
+        (Some(feet_position), into_void)
+    };
+
+    let fall_distance = feet_position.map(|feet| pearl_position.y - feet.y);
+    let fall_damage = match (feet_position, fall_distance) {
+        (Some(feet), Some(fall_distance))
+            if world.fluid_at(&player_box(feet)) != Some(FluidKind::Water) =>
+        {
+            (fall_distance - PLAYER_SAFE_FALL_DISTANCE).ceil().max(0.0)
+        }
+        _ => 0.0,
+    };
+    let damage = PEARL_TELEPORT_DAMAGE + fall_damage;
+    let feet_position = feet_position.map(|feet| feet + offset);
+
+    Arrival {
+        teleport_position: pearl_position + offset,
+        feet_position,
+        feet_block: feet_position.map(|feet| {
+            (
+                feet.x.floor() as i32,
+                feet.y.floor() as i32,
+                feet.z.floor() as i32,
+            )
+        }),
+        fall_distance,
+        damage,
+        survivable: !into_void && damage < PLAYER_MAX_HEALTH,
+        suffocating,
+        into_void,
+    }
+}
+
+/// Drops the player box straight down until it rests on a box, or reaches the void kill line.
+fn fall_to_ground(position: Space3D, world: &CollisionWorld) -> (Space3D, bool) {
+    let kill_y = world
+        .void_kill_y()
+        .unwrap_or_else(|| DimensionSettings::default().void_kill_y());
+    let max_drop = (position.y - kill_y).max(0.0);
+
+    let start = player_box(position);
+    let path = start.expand_towards(0.0, -max_drop, 0.0);
+    let drop = world
+        .query(&path)
+        .iter()
+        .fold(-max_drop, |drop, obstacle| obstacle.y_offset(&start, drop));
+
+    let into_void = drop <= -max_drop + FLOAT_PRECISION_EPSILON;
+    (
+        Space3D::new(position.x, position.y + drop, position.z),
+        into_void,
+    )
+}
+
+fn is_suffocating(position: Space3D, world: &CollisionWorld) -> bool {
+    let half_width = PLAYER_WIDTH * SUFFOCATION_CHECK_WIDTH_FACTOR / 2.0;
+    let half_height = SUFFOCATION_CHECK_HEIGHT / 2.0;
+    let eye_y = position.y + PLAYER_EYE_HEIGHT;
+    let eye_box = AABBBox::new(
+        position.x - half_width,
+        eye_y - half_height,
+        position.z - half_width,
+        position.x + half_width,
+        eye_y + half_height,
+        position.z + half_width,
+    );
+
+    world
+        .query(&eye_box)
+        .iter()
+        .any(|aabb| aabb.intersects(&eye_box))
+}
+
+fn player_box(feet: Space3D) -> AABBBox {
+    let half_width = PLAYER_WIDTH / 2.0;
+    AABBBox::new(
+        feet.x - half_width,
+        feet.y,
+        feet.z - half_width,
+        feet.x + half_width,
+        feet.y + PLAYER_HEIGHT,
+        feet.z + half_width,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::world::dimension::Dimension;
+
+    #[test]
+    fn falls_onto_terrain_below_the_nether_ceiling() {
+        let floor = AABBBox::new(-8.0, 60.0, -8.0, 8.0, 61.0, 8.0);
+        let world = CollisionWorld::new(vec![floor]).with_dimension(Dimension::Nether.settings());
+        let arrival = analyze_arrival(
+            Space3D::new(0.5, 70.0, 0.5),
+            &world,
+            Space3D::new(100.0, 0.0, 0.0),
+        );
+
+        assert_eq!(arrival.feet_position, Some(Space3D::new(100.5, 61.0, 0.5)));
+        assert_eq!(arrival.feet_block, Some((100, 61, 0)));
+        assert_eq!(arrival.fall_distance, Some(9.0));
+        assert_eq!(arrival.damage, PEARL_TELEPORT_DAMAGE + 6.0);
+        assert!(!arrival.into_void);
+
+        let off_the_edge =
+            analyze_arrival(Space3D::new(20.0, 70.0, 0.5), &world, Space3D::default());
+        assert!(off_the_edge.into_void);
+        assert!(!off_the_edge.survivable);
+    }
+}
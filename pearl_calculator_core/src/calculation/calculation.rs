@@ -1,19 +1,31 @@
-use crate::calculation::inputs::Cannon;
-use crate::calculation::results::TNTResult;
+use crate::calculation::inputs::{Cannon, GroupCannon, SpreadParams, Thrower};
+use crate::calculation::results::{
+    ChunkPathReport, GroupTNTResult, LandingDistribution, Sensitivity, TNTResult, ThrowResult,
+};
 use crate::physics::constants::constants::FLOAT_PRECISION_EPSILON;
+use crate::physics::entities::entity_kind::EntityKind;
 use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::dimension::Dimension;
 use crate::physics::world::direction::Direction;
 use crate::physics::world::space::Space3D;
 
+/// `destination` is given in `destination_dimension` coordinates and is converted into the
+/// cannon's dimension before solving.
 pub fn calculate_tnt_amount(
     cannon: &Cannon,
     destination: Space3D,
+    destination_dimension: Dimension,
     max_tnt: u32,
     max_vertical_tnt: Option<u32>,
     max_ticks: u32,
     max_distance: f64,
+    world_collisions: &CollisionWorld,
     version: PearlVersion,
 ) -> Vec<TNTResult> {
+    let requested_destination = destination;
+    let destination = destination_dimension.convert(destination, cannon.dimension);
+
     let pearl_start_absolute_pos = cannon.pearl.position + cannon.pearl.offset;
     let true_distance = destination - pearl_start_absolute_pos;
 
@@ -23,8 +35,14 @@ pub fn calculate_tnt_amount(
 
     let flight_direction =
         Direction::from_angle(pearl_start_absolute_pos.angle_to_yaw(&destination));
-    let (red_vec, blue_vec, vert_vec) =
-        super::vectors::resolve_vectors_for_direction(cannon, flight_direction);
+    let Some((red_vec, blue_vec, vert_vec)) = super::vectors::resolve_vectors_for_direction(
+        cannon,
+        flight_direction,
+        EntityKind::Pearl,
+        world_collisions,
+    ) else {
+        return Vec::new();
+    };
 
     let solver_input = super::solver::SolverInput {
         red_vec,
@@ -50,7 +68,7 @@ pub fn calculate_tnt_amount(
     let candidates = super::optimizer::generate_candidates(theoretical_groups, &search_params);
 
     let max_distance_sq = max_distance * max_distance;
-    super::trace::validate_candidates(
+    let mut results = super::trace::validate_candidates(
         candidates,
         red_vec,
         blue_vec,
@@ -60,8 +78,208 @@ pub fn calculate_tnt_amount(
         cannon.pearl.offset,
         destination,
         max_distance_sq,
+        world_collisions,
+        version,
+    );
+
+    let offset = cannon.pearl.offset;
+    for result in &mut results {
+        result.arrival = Some(super::arrival::analyze_arrival(
+            result.pearl_end_pos - offset,
+            world_collisions,
+            offset,
+        ));
+    }
+
+    super::portal::attach_conversions(
+        &mut results,
+        cannon.dimension,
+        destination_dimension,
+        requested_destination,
+    );
+    results
+}
+
+pub fn calculate_group_tnt_amount(
+    cannon: &GroupCannon,
+    destination: Space3D,
+    max_ticks: u32,
+    max_distance: f64,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Vec<GroupTNTResult> {
+    let pearl_start_absolute_pos = cannon.pearl.position + cannon.pearl.offset;
+    let true_distance = destination - pearl_start_absolute_pos;
+
+    if true_distance.length_sq() < FLOAT_PRECISION_EPSILON || cannon.groups.is_empty() {
+        return Vec::new();
+    }
+
+    let group_vecs = super::vectors::resolve_group_vectors(cannon, world_collisions, version);
+    let max_counts: Vec<u32> = cannon.groups.iter().map(|g| g.max_count).collect();
+    let is_3d_solve =
+        cannon.groups.iter().any(|g| g.vertical) && super::solver::groups_span_3d(&group_vecs);
+
+    let solver_input = super::solver::GroupSolverInput {
+        group_vecs: group_vecs.clone(),
+        max_counts: max_counts.clone(),
+        is_3d_solve,
+        start_pos: pearl_start_absolute_pos,
+        destination,
+        max_ticks,
+        version,
+    };
+    let theoretical_groups = super::solver::solve_theoretical_groups(&solver_input);
+
+    let search_params = super::optimizer::GroupSearchParams {
+        max_counts,
+        search_radius: 3,
+    };
+    let candidates =
+        super::optimizer::generate_group_candidates(theoretical_groups, &search_params);
+
+    let max_distance_sq = max_distance * max_distance;
+    super::trace::validate_group_candidates(
+        candidates,
+        &group_vecs,
+        cannon.pearl.position,
+        cannon.pearl.motion,
+        cannon.pearl.offset,
+        destination,
+        max_distance_sq,
+        is_3d_solve,
+        world_collisions,
         version,
     )
 }
 
 pub use super::trace::{calculate_pearl_trace, calculate_raw_trace};
+
+/// Spread of a [`calculate_pearl_trace`] shot once TNT ignition randomness is sampled.
+/// `destination` is in world coordinates.
+pub fn calculate_landing_distribution(
+    cannon: &Cannon,
+    red_tnt: u32,
+    blue_tnt: u32,
+    vertical_tnt: u32,
+    direction: Direction,
+    destination: Space3D,
+    params: &SpreadParams,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<LandingDistribution> {
+    super::spread::landing_distribution(
+        cannon,
+        red_tnt,
+        blue_tnt,
+        vertical_tnt,
+        direction,
+        destination,
+        params,
+        max_ticks,
+        world_collisions,
+        version,
+    )
+}
+
+/// How the landing point of `result` responds to one TNT more or fewer per group and to
+/// small errors in the pearl's start state. `cannon` must be the one that produced it.
+pub fn calculate_sensitivity(
+    cannon: &Cannon,
+    result: &TNTResult,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<Sensitivity> {
+    super::sensitivity::tnt_sensitivity(
+        cannon,
+        result.red,
+        result.blue,
+        result.vertical,
+        result.direction,
+        result.tick,
+        world_collisions,
+        version,
+    )
+}
+
+pub use super::throw::throw_state;
+
+pub use super::dispenser::{dispense_pearl, sample_dispensed_pearls};
+
+/// Yaw and pitch for throwing a pearl by hand onto `destination`, one result per arc that
+/// reaches it, low arc first. Positions are in world coordinates.
+pub fn calculate_throw(
+    thrower: &Thrower,
+    destination: Space3D,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Vec<ThrowResult> {
+    super::throw::solve_throw(thrower, destination, max_ticks, world_collisions, version)
+}
+
+/// Chunks, region files and a chunk loader layout for a trace in world coordinates, such
+/// as `CalculationResult::pearl_trace`.
+pub fn calculate_chunk_path(trace: &[Space3D], simulation_distance: u32) -> ChunkPathReport {
+    super::chunk_path::chunk_path_report(trace, simulation_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::inputs::Pearl;
+    use crate::physics::world::layout_direction::LayoutDirection;
+    use crate::settings::CannonMode;
+
+    fn nether_cannon() -> Cannon {
+        let tnt = |x: f64, z: f64| Space3D::new(x, 60.0, z);
+        Cannon {
+            pearl: Pearl {
+                position: Space3D::new(0.5, 60.34, 0.5),
+                motion: Space3D::default(),
+                offset: Space3D::default(),
+            },
+            red_tnt_override: None,
+            blue_tnt_override: None,
+            vertical_tnt: None,
+            mode: CannonMode::Standard,
+            north_west_tnt: tnt(-0.884999, -0.884999),
+            north_east_tnt: tnt(1.884999, -0.884999),
+            south_west_tnt: tnt(-0.884999, 1.884999),
+            south_east_tnt: tnt(1.884999, 1.884999),
+            north_tnt: tnt(0.5, -0.884999),
+            south_tnt: tnt(0.5, 1.884999),
+            west_tnt: tnt(-0.884999, 0.5),
+            east_tnt: tnt(1.884999, 0.5),
+            default_red_duper: Some(LayoutDirection::NorthWest),
+            default_blue_duper: Some(LayoutDirection::NorthEast),
+            dimension: Dimension::Nether,
+        }
+    }
+
+    #[test]
+    fn nether_results_without_terrain_leave_the_ground_unknown() {
+        let world = CollisionWorld::default().with_dimension(Dimension::Nether.settings());
+        let results = calculate_tnt_amount(
+            &nether_cannon(),
+            Space3D::new(20.0, 0.0, 120.0),
+            Dimension::Nether,
+            500,
+            None,
+            200,
+            10.0,
+            &world,
+            PearlVersion::Post1212,
+        );
+
+        assert!(!results.is_empty());
+        for result in &results {
+            let arrival = result.arrival.expect("every result gets an arrival");
+            assert_eq!(arrival.feet_position, None);
+            assert_eq!(arrival.fall_distance, None);
+            assert!(!arrival.into_void);
+            assert!(arrival.survivable);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::calculation::results::{ChunkPathReport, ChunkVisit};
+use crate::physics::world::chunk::{ChunkPos, entity_ticking_radius, for_each_chunk_on_segment};
+use crate::physics::world::space::Space3D;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Chunks along a trace in world coordinates, where index `i` is the position after
+/// tick `i`. The path between two positions is walked chunk by chunk, so chunks the pearl
+/// only crosses between two ticks are listed too.
+pub fn trace_chunk_visits(trace: &[Space3D]) -> Vec<ChunkVisit> {
+    let mut visits: Vec<ChunkVisit> = Vec::new();
+    let mut index_of: HashMap<ChunkPos, usize> = HashMap::new();
+
+    let mut visit = |chunk: ChunkPos, entry: Space3D, exit: Space3D, first: u32, last: u32| {
+        let index = *index_of.entry(chunk).or_insert_with(|| {
+            visits.push(ChunkVisit {
+                chunk,
+                first_tick: first,
+                last_tick: last,
+                entry,
+                exit,
+            });
+            visits.len() - 1
+        });
+        let chunk_visit = &mut visits[index];
+        chunk_visit.exit = exit;
+        chunk_visit.last_tick = chunk_visit.last_tick.max(last);
+    };
+
+    if let [point] = trace {
+        visit(ChunkPos::from_position(*point), *point, *point, 0, 0);
+    }
+    for (index, segment) in trace.windows(2).enumerate() {
+        let (from, to) = (segment[0], segment[1]);
+        let delta = to - from;
+        let tick = index as u32;
+        // The segment is the movement of tick `tick + 1`: the chunk it starts in holds the
+        // pearl at `tick`, and every chunk it crosses after that is entered during `tick + 1`.
+        for_each_chunk_on_segment(from, to, |chunk, t0, t1| {
+            let first = if t0 == 0.0 { tick } else { tick + 1 };
+            let last = if t1 >= 1.0 { tick + 1 } else { first };
+            visit(chunk, from + delta * t0, from + delta * t1, first, last);
+        });
+    }
+
+    visits
+}
+
+/// Loader chunks that keep every chunk entity-ticking at the given simulation distance.
+///
+/// Greedy: the first uncovered chunk along the path gets the loader, among those covering
+/// it, that covers the most other uncovered chunks. This is optimal along a straight line
+/// of chunks and close to it for curved paths.
+pub fn plan_chunk_loaders(chunks: &[ChunkPos], simulation_distance: u32) -> Vec<ChunkPos> {
+    let radius = entity_ticking_radius(simulation_distance);
+    let reach = radius as i32;
+
+    let mut uncovered: HashSet<ChunkPos> = chunks.iter().copied().collect();
+    let mut loaders = Vec::new();
+
+    for &chunk in chunks {
+        if !uncovered.contains(&chunk) {
+            continue;
+        }
+
+        let nearby: Vec<ChunkPos> = uncovered
+            .iter()
+            .filter(|other| other.distance(&chunk) <= 2 * radius)
+            .copied()
+            .collect();
+        let loader = (-reach..=reach)
+            .flat_map(|dx| (-reach..=reach).map(move |dz| (dx, dz)))
+            .map(|(dx, dz)| ChunkPos::new(chunk.x + dx, chunk.z + dz))
+            .max_by_key(|candidate| {
+                nearby
+                    .iter()
+                    .filter(|other| other.distance(candidate) <= radius)
+                    .count()
+            })
+            .unwrap_or(chunk);
+
+        uncovered.retain(|other| other.distance(&loader) > radius);
+        loaders.push(loader);
+    }
+
+    loaders
+}
+
+pub fn chunk_path_report(trace: &[Space3D], simulation_distance: u32) -> ChunkPathReport {
+    let chunks = trace_chunk_visits(trace);
+    let positions: Vec<ChunkPos> = chunks.iter().map(|visit| visit.chunk).collect();
+    let regions: BTreeSet<(i32, i32)> = positions.iter().map(ChunkPos::region).collect();
+
+    ChunkPathReport {
+        loaders: plan_chunk_loaders(&positions, simulation_distance),
+        regions: regions.into_iter().collect(),
+        chunks,
+    }
+}
@@ -0,0 +1,61 @@
+use crate::calculation::inputs::{Dispenser, Pearl};
+use crate::physics::constants::constants::{
+    DISPENSED_PROJECTILE_LIFT, DISPENSED_PROJECTILE_SPEED, DISPENSER_INACCURACY,
+    DISPENSER_SPAWN_OFFSET, PROJECTILE_SPREAD_PER_INACCURACY,
+};
+use crate::physics::world::space::Space3D;
+use crate::utils::java_random::JavaRandom;
+
+/// Pearl a dispenser shoots with its random spread averaged out. Vanilla dispensers drop
+/// ender pearls as items instead; this follows the projectile behaviour they use for
+/// snowballs and eggs, which is what servers and mods that shoot pearls reuse.
+///
+/// The offset is the dispenser's column, so the position is relative to it like a
+/// configured cannon's pearl.
+pub fn dispense_pearl(dispenser: &Dispenser) -> Pearl {
+    to_pearl(dispenser, aim(dispenser) * DISPENSED_PROJECTILE_SPEED)
+}
+
+/// `count` pearls with the spread applied, drawn from one Java `Random` seeded with `seed`
+/// so the same seed gives the same samples.
+pub fn sample_dispensed_pearls(dispenser: &Dispenser, count: usize, seed: i64) -> Vec<Pearl> {
+    let aim = aim(dispenser);
+    let spread = PROJECTILE_SPREAD_PER_INACCURACY * DISPENSER_INACCURACY;
+    let mut random = JavaRandom::new(seed);
+
+    (0..count)
+        .map(|_| {
+            let deviation = Space3D::new(
+                random.next_gaussian() * spread,
+                random.next_gaussian() * spread,
+                random.next_gaussian() * spread,
+            );
+            to_pearl(dispenser, (aim + deviation) * DISPENSED_PROJECTILE_SPEED)
+        })
+        .collect()
+}
+
+/// Unit aim before the spread: the facing, tilted up by adding to its vertical step in
+/// `f32` like vanilla does.
+fn aim(dispenser: &Dispenser) -> Space3D {
+    let step = dispenser.facing.normal();
+    let direction = Space3D::new(
+        step.x,
+        (step.y as f32 + DISPENSED_PROJECTILE_LIFT) as f64,
+        step.z,
+    );
+    direction / direction.length()
+}
+
+fn to_pearl(dispenser: &Dispenser, motion: Space3D) -> Pearl {
+    let (x, y, z) = dispenser.block;
+    let center = Space3D::new(x as f64 + 0.5, y as f64 + 0.5, z as f64 + 0.5);
+    let spawn = center + dispenser.facing.normal() * DISPENSER_SPAWN_OFFSET;
+    let offset = Space3D::new(x as f64, 0.0, z as f64);
+
+    Pearl {
+        position: spawn - offset,
+        motion,
+        offset,
+    }
+}
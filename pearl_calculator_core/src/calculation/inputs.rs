@@ -1,3 +1,6 @@
+use crate::physics::explosion::source::ExplosionSource;
+use crate::physics::world::block_face::BlockFace;
+use crate::physics::world::dimension::Dimension;
 use crate::physics::world::layout_direction::LayoutDirection;
 use crate::physics::world::space::Space3D;
 use crate::settings::{CannonMode, CannonSettings};
@@ -14,6 +17,8 @@ pub struct GeneralData {
 pub struct TNT {
     pub position: Space3D,
     pub fuse: u32,
+    #[serde(default)]
+    pub source: ExplosionSource,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -34,8 +39,65 @@ pub struct Cannon {
     pub north_east_tnt: Space3D,
     pub south_west_tnt: Space3D,
     pub south_east_tnt: Space3D,
+    pub north_tnt: Space3D,
+    pub south_tnt: Space3D,
+    pub west_tnt: Space3D,
+    pub east_tnt: Space3D,
     pub default_red_duper: Option<LayoutDirection>,
     pub default_blue_duper: Option<LayoutDirection>,
+    pub dimension: Dimension,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TntGroup {
+    pub name: String,
+    pub position: Space3D,
+    /// Upper bound for this group's count; `0` leaves it unbounded.
+    pub max_count: u32,
+    /// Vertical groups make the solver target the destination's y as well,
+    /// like the optional vertical TNT of [`Cannon`].
+    #[serde(default)]
+    pub vertical: bool,
+    #[serde(default)]
+    pub source: ExplosionSource,
+}
+
+/// The randomness sampled when estimating where a cannon's pearl lands.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpreadParams {
+    pub samples: u32,
+    pub seed: i64,
+    /// Ticks each TNT drifts along the floor of its chamber after the ignition kick.
+    pub fuse: u32,
+    /// Standard deviation of each TNT's horizontal spawn position, for dupers that do not
+    /// spawn it at the block center.
+    pub spawn_spread: f64,
+    /// Landings within this horizontal distance of the destination count as hits.
+    pub hit_radius: f64,
+}
+
+/// A dispenser shooting a pearl out of its front face.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dispenser {
+    pub block: (i32, i32, i32),
+    pub facing: BlockFace,
+}
+
+/// A player throwing a pearl by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Thrower {
+    pub eye_position: Space3D,
+    /// Carried over into the pearl, the vertical part only while airborne.
+    pub motion: Space3D,
+    pub on_ground: bool,
+}
+
+/// A cannon described by an arbitrary list of named TNT groups instead of
+/// the fixed red/blue/vertical layout of [`Cannon`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupCannon {
+    pub pearl: Pearl,
+    pub groups: Vec<TntGroup>,
 }
 
 impl Cannon {
@@ -54,8 +116,13 @@ impl Cannon {
             north_east_tnt: settings.north_east_tnt,
             south_west_tnt: settings.south_west_tnt,
             south_east_tnt: settings.south_east_tnt,
+            north_tnt: settings.north_tnt,
+            south_tnt: settings.south_tnt,
+            west_tnt: settings.west_tnt,
+            east_tnt: settings.east_tnt,
             default_red_duper: settings.default_red_direction,
             default_blue_duper: settings.default_blue_direction,
+            dimension: settings.dimension,
         }
     }
 }
@@ -63,3 +63,42 @@ pub fn generate_candidates(
 
     unique_candidates.into_iter().collect()
 }
+
+pub struct GroupSearchParams {
+    pub max_counts: Vec<u32>,
+    pub search_radius: i32,
+}
+
+pub fn generate_group_candidates(
+    theoretical_groups: HashMap<Vec<u32>, Vec<u32>>,
+    params: &GroupSearchParams,
+) -> Vec<(Vec<u32>, Vec<u32>)> {
+    let mut unique_candidates: HashMap<Vec<u32>, Vec<u32>> = HashMap::new();
+
+    for (center, valid_ticks) in theoretical_groups {
+        unique_candidates
+            .entry(center.clone())
+            .or_default()
+            .extend(valid_ticks.iter().cloned());
+
+        for group_index in 0..center.len() {
+            let max_count = params.max_counts.get(group_index).copied().unwrap_or(0);
+
+            for offset in -params.search_radius..=params.search_radius {
+                let current = center[group_index] as i64 + offset as i64;
+                if offset == 0 || current < 0 || (max_count > 0 && current > max_count as i64) {
+                    continue;
+                }
+
+                let mut counts = center.clone();
+                counts[group_index] = current as u32;
+                unique_candidates
+                    .entry(counts)
+                    .or_default()
+                    .extend(valid_ticks.iter().cloned());
+            }
+        }
+    }
+
+    unique_candidates.into_iter().collect()
+}
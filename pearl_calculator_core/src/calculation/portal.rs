@@ -0,0 +1,49 @@
+use crate::calculation::results::{DimensionConversion, PortalRounding, TNTResult};
+use crate::physics::world::dimension::Dimension;
+use crate::physics::world::space::Space3D;
+
+/// Attaches the destination-dimension view to every result. `destination` is in the
+/// destination's own coordinates.
+pub fn attach_conversions(
+    results: &mut [TNTResult],
+    from: Dimension,
+    to: Dimension,
+    destination: Space3D,
+) {
+    if from == to {
+        return;
+    }
+
+    for result in results {
+        result.conversion = Some(DimensionConversion {
+            dimension: to,
+            pearl_end_pos: from.convert(result.pearl_end_pos, to),
+            portal_rounding: portal_rounding(result.pearl_end_pos, from, destination, to),
+        });
+    }
+}
+
+/// Portals link by the block the player stands in, so only the nether block column counts:
+/// eight overworld blocks share one, and a landing one column off arrives up to 8 blocks away.
+fn portal_rounding(
+    landing: Space3D,
+    from: Dimension,
+    destination: Space3D,
+    to: Dimension,
+) -> Option<PortalRounding> {
+    if from != Dimension::Nether && to != Dimension::Nether {
+        return None;
+    }
+
+    let landing_block = nether_block(from.convert(landing, Dimension::Nether));
+    let destination_block = nether_block(to.convert(destination, Dimension::Nether));
+
+    (landing_block != destination_block).then_some(PortalRounding {
+        landing_block,
+        destination_block,
+    })
+}
+
+fn nether_block(position: Space3D) -> (i32, i32) {
+    (position.x.floor() as i32, position.z.floor() as i32)
+}
@@ -1,4 +1,8 @@
+use crate::physics::world::block_face::BlockFace;
+use crate::physics::world::chunk::ChunkPos;
+use crate::physics::world::dimension::Dimension;
 use crate::physics::world::direction::Direction;
+use crate::physics::world::fluid::FluidKind;
 use crate::physics::world::space::Space3D;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -14,6 +18,108 @@ pub struct TNTResult {
     pub pearl_end_pos: Space3D,
     pub pearl_end_motion: Space3D,
     pub direction: Direction,
+    /// The landing seen from the destination's dimension, when it differs from the cannon's.
+    pub conversion: Option<DimensionConversion>,
+    pub arrival: Option<Arrival>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DimensionConversion {
+    pub dimension: Dimension,
+    pub pearl_end_pos: Space3D,
+    pub portal_rounding: Option<PortalRounding>,
+}
+
+/// The nether block column the pearl lands in is not the one the destination falls in, so a
+/// portal built at the landing spot links to a different overworld area.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortalRounding {
+    pub landing_block: (i32, i32),
+    pub destination_block: (i32, i32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupTNTResult {
+    pub distance: f64,
+    pub tick: u32,
+    pub counts: Vec<u32>,
+    pub yaw: f64,
+    pub pitch: f64,
+    pub total: u32,
+    pub pearl_end_pos: Space3D,
+    pub pearl_end_motion: Space3D,
+    pub direction: Direction,
+}
+
+/// Which of the two pitches that reach a destination a throw uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrowArc {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrowResult {
+    pub arc: ThrowArc,
+    /// Rounded to the tenth of a degree F3 shows, with yaw wrapped to -180..180 the same
+    /// way. `/tp` sets them exactly, and the rest of the result is simulated with them.
+    pub yaw: f64,
+    pub pitch: f64,
+    pub pearl_start_pos: Space3D,
+    pub pearl_start_motion: Space3D,
+    /// Where the pearl passes closest to the destination, and the tick it gets there.
+    pub tick: u32,
+    pub pearl_end_pos: Space3D,
+    pub distance: f64,
+    pub status: FlightStatus,
+}
+
+/// Where a cannon's pearl ends up over many sampled shots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandingDistribution {
+    /// Tick samples that do not land are taken at: when the nominal shot lands, or passes
+    /// closest to the destination if it does not.
+    pub tick: u32,
+    pub landings: Vec<Space3D>,
+    pub mean: Space3D,
+    /// Of the horizontal landing spot, as `[[xx, xz], [xz, zz]]`.
+    pub covariance: [[f64; 2]; 2],
+    pub ellipse: ConfidenceEllipse,
+    pub hit_probability: f64,
+}
+
+/// Horizontal region holding 95% of landings if they are normally distributed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceEllipse {
+    pub semi_major: f64,
+    pub semi_minor: f64,
+    /// Direction of the major axis, in degrees from +X towards +Z.
+    pub angle: f64,
+}
+
+/// How the landing point of a [`TNTResult`] moves when its inputs are slightly off. Every
+/// displacement is measured at the result's tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sensitivity {
+    pub tick: u32,
+    pub landing: Space3D,
+    pub red: TntSensitivity,
+    pub blue: TntSensitivity,
+    /// `None` when the cannon has no vertical TNT.
+    pub vertical: Option<TntSensitivity>,
+    /// Landing displacement per block of error in the pearl's start position, one entry per
+    /// axis (X, Y, Z). Together with `motion` these are the columns of the Jacobian.
+    pub position: [Space3D; 3],
+    /// Landing displacement per block per tick of error in the pearl's launch motion.
+    pub motion: [Space3D; 3],
+}
+
+/// Landing displacement from changing one TNT group by a single TNT.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TntSensitivity {
+    pub more: Space3D,
+    /// `None` when the group is already empty.
+    pub fewer: Option<Space3D>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -25,4 +131,86 @@ pub struct CalculationResult {
     pub tick: u32,
     pub final_motion: Space3D,
     pub distance: f64,
+    pub hit: Option<HitResult>,
+    pub fluid_ticks: Vec<FluidTick>,
+    pub status: FlightStatus,
+    pub frozen: Option<FrozenResult>,
+    /// Set when the pearl is still around at the end of the trace, taking its last position
+    /// as the teleport target.
+    pub arrival: Option<Arrival>,
+}
+
+/// How a simulated flight ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightStatus {
+    /// Still flying when the tick limit was reached.
+    InFlight,
+    /// Hit a block; `CalculationResult::hit` has the details.
+    Landed,
+    /// Fell below the dimension's void kill line and was discarded.
+    RemovedInVoid,
+    /// Entered a chunk that does not tick entities; `CalculationResult::frozen` says where.
+    Frozen,
+}
+
+/// The thrower after the teleport, assuming full health and no armor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arrival {
+    /// Feet position right after the teleport, which is the pearl's position.
+    pub teleport_position: Space3D,
+    /// Feet position once the player has fallen onto whatever is below, or `None` when the
+    /// world has no terrain to fall onto.
+    pub feet_position: Option<Space3D>,
+    pub feet_block: Option<(i32, i32, i32)>,
+    pub fall_distance: Option<f64>,
+    /// Pearl damage plus fall damage, in half hearts. Only the pearl's when the ground is
+    /// unknown.
+    pub damage: f64,
+    pub survivable: bool,
+    /// The player's eyes end up inside a block right after the teleport.
+    pub suffocating: bool,
+    /// Nothing below stops the fall before the void kill line.
+    pub into_void: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrozenResult {
+    /// First tick the pearl did not run.
+    pub tick: u32,
+    pub chunk: ChunkPos,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitResult {
+    pub tick: u32,
+    pub position: Space3D,
+    pub face: BlockFace,
+}
+
+/// A tick whose drag was taken while the pearl was inside a fluid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FluidTick {
+    pub tick: u32,
+    pub kind: FluidKind,
+}
+
+/// A chunk a trajectory crosses. The tick range runs from the tick the pearl enters the
+/// chunk to the last one it is inside it; a chunk only crossed between two ticks gets the
+/// tick whose movement crosses it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkVisit {
+    pub chunk: ChunkPos,
+    pub first_tick: u32,
+    pub last_tick: u32,
+    pub entry: Space3D,
+    pub exit: Space3D,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkPathReport {
+    /// In the order the trajectory first enters them.
+    pub chunks: Vec<ChunkVisit>,
+    /// Region files (`r.x.z.mca`) holding those chunks.
+    pub regions: Vec<(i32, i32)>,
+    pub loaders: Vec<ChunkPos>,
 }
@@ -0,0 +1,99 @@
+use crate::calculation::inputs::{Cannon, GeneralData};
+use crate::calculation::results::{Sensitivity, TntSensitivity};
+use crate::calculation::simulation;
+use crate::physics::constants::constants::{SENSITIVITY_MOTION_STEP, SENSITIVITY_POSITION_STEP};
+use crate::physics::entities::entity_kind::EntityKind;
+use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::direction::Direction;
+use crate::physics::world::space::Space3D;
+
+/// Flies the shot again with each input nudged and reports how far the landing point at
+/// `tick` moves. TNT groups change by a whole TNT, while the pearl's start position and
+/// motion use central differences so the result is a per-unit Jacobian.
+pub fn tnt_sensitivity(
+    cannon: &Cannon,
+    red_tnt: u32,
+    blue_tnt: u32,
+    vertical_tnt: u32,
+    direction: Direction,
+    tick: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<Sensitivity> {
+    let (red_vec, blue_vec, vert_vec) = super::vectors::resolve_vectors_for_direction(
+        cannon,
+        direction,
+        EntityKind::Pearl,
+        world_collisions,
+    )?;
+    let position = cannon.pearl.position;
+    let motion = cannon.pearl.motion
+        + red_vec * (red_tnt as f64)
+        + blue_vec * (blue_tnt as f64)
+        + vert_vec * (vertical_tnt as f64);
+
+    let land = |position: Space3D, motion: Space3D| {
+        let data = GeneralData {
+            pearl_position: position,
+            pearl_motion: motion,
+            tnt_charges: Vec::new(),
+        };
+        simulation::run(
+            &data,
+            EntityKind::Pearl,
+            None,
+            tick,
+            world_collisions,
+            Some(cannon.pearl.offset),
+            version,
+        )
+        .map(|result| result.landing_position)
+    };
+
+    let landing = land(position, motion)?;
+    let group = |vec: Space3D, count: u32| -> Option<TntSensitivity> {
+        let more = land(position, motion + vec)? - landing;
+        let fewer = if count > 0 {
+            Some(land(position, motion - vec)? - landing)
+        } else {
+            None
+        };
+        Some(TntSensitivity { more, fewer })
+    };
+
+    let vertical = match cannon.vertical_tnt {
+        Some(_) => Some(group(vert_vec, vertical_tnt)?),
+        None => None,
+    };
+
+    Some(Sensitivity {
+        tick,
+        landing,
+        red: group(red_vec, red_tnt)?,
+        blue: group(blue_vec, blue_tnt)?,
+        vertical,
+        position: jacobian(SENSITIVITY_POSITION_STEP, |error| {
+            land(position + error, motion)
+        })?,
+        motion: jacobian(SENSITIVITY_MOTION_STEP, |error| {
+            land(position, motion + error)
+        })?,
+    })
+}
+
+/// Central difference of `land` along each axis, scaled to one unit of error.
+fn jacobian(step: f64, land: impl Fn(Space3D) -> Option<Space3D>) -> Option<[Space3D; 3]> {
+    let axes = [
+        Space3D::new(1.0, 0.0, 0.0),
+        Space3D::new(0.0, 1.0, 0.0),
+        Space3D::new(0.0, 0.0, 1.0),
+    ];
+    let mut columns = [Space3D::default(); 3];
+    for (column, axis) in columns.iter_mut().zip(axes) {
+        let ahead = land(axis * step)?;
+        let behind = land(axis * -step)?;
+        *column = (ahead - behind) / (2.0 * step);
+    }
+    Some(columns)
+}
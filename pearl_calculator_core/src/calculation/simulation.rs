@@ -1,15 +1,20 @@
 use crate::calculation::inputs::GeneralData;
-use crate::calculation::results::CalculationResult;
-use crate::physics::aabb::aabb_box::AABBBox;
-use crate::physics::constants::constants::{
-    FLOAT_PRECISION_EPSILON, PEARL_EXPLOSION_Y_FACTOR, PEARL_HEIGHT, TNT_ENTITY_Y_OFFSET,
-    TNT_EXPLOSION_RADIUS,
+use crate::calculation::results::{
+    CalculationResult, FlightStatus, FluidTick, FrozenResult, HitResult,
 };
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::constants::constants::FLOAT_PRECISION_EPSILON;
+use crate::physics::entities::entities::{EntityData, EntityTrait};
+use crate::physics::entities::entity_kind::EntityKind;
 use crate::physics::entities::movement::{
     MovementLegacy, MovementPost1205, MovementPost1212, PearlMovement, PearlVersion,
 };
 use crate::physics::entities::pearl_entities::PearlEntity;
 use crate::physics::entities::tnt_entities::TNTEntity;
+use crate::physics::explosion::exposure;
+use crate::physics::explosion::source::ExplosionSource;
+use crate::physics::world::chunk::ChunkPos;
+use crate::physics::world::collision_world::CollisionWorld;
 use crate::physics::world::space::Space3D;
 use std::collections::LinkedList;
 
@@ -20,39 +25,58 @@ pub struct SimResult {
     pub distance: f64,
 }
 
+/// Flies `kind` from the pearl state in `data`. Only pearls get an arrival analysis.
 pub fn run(
     data: &GeneralData,
+    kind: EntityKind,
     destination: Option<Space3D>,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     offset: Option<Space3D>,
     version: PearlVersion,
 ) -> Option<CalculationResult> {
     match version {
-        PearlVersion::Legacy => {
-            run_internal::<MovementLegacy>(data, destination, max_ticks, world_collisions, offset)
-        }
-        PearlVersion::Post1205 => {
-            run_internal::<MovementPost1205>(data, destination, max_ticks, world_collisions, offset)
-        }
-        PearlVersion::Post1212 => {
-            run_internal::<MovementPost1212>(data, destination, max_ticks, world_collisions, offset)
-        }
+        PearlVersion::Legacy => run_internal::<MovementLegacy>(
+            data,
+            kind,
+            destination,
+            max_ticks,
+            world_collisions,
+            offset,
+        ),
+        PearlVersion::Post1205 => run_internal::<MovementPost1205>(
+            data,
+            kind,
+            destination,
+            max_ticks,
+            world_collisions,
+            offset,
+        ),
+        PearlVersion::Post1212 => run_internal::<MovementPost1212>(
+            data,
+            kind,
+            destination,
+            max_ticks,
+            world_collisions,
+            offset,
+        ),
     }
 }
 
 fn run_internal<M: PearlMovement + Clone>(
     data: &GeneralData,
+    kind: EntityKind,
     destination: Option<Space3D>,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     offset: Option<Space3D>,
 ) -> Option<CalculationResult> {
-    let mut pearl = PearlEntity::<M>::new(data.pearl_position, data.pearl_motion);
+    let mut pearl = PearlEntity::<M>::with_kind(data.pearl_position, data.pearl_motion, kind);
+    let profile = kind.profile();
     let mut tnt_entities: Vec<TNTEntity> = data
         .tnt_charges
         .iter()
-        .map(|tnt| TNTEntity::new(tnt.position, tnt.fuse))
+        .map(|tnt| TNTEntity::new(tnt.position, tnt.fuse, tnt.source))
         .collect();
 
     let mut traces: LinkedList<Space3D> = LinkedList::new();
@@ -61,25 +85,88 @@ fn run_internal<M: PearlMovement + Clone>(
     traces.push_back(pearl.data.position);
     motion_traces.push_back(pearl.data.motion);
 
+    let mut hit_result = None;
+    let mut end_tick = max_ticks;
+    let mut fluid_ticks = Vec::new();
+    let mut status = FlightStatus::InFlight;
+    let mut frozen = None;
+    let world_offset = offset.unwrap_or_default();
+
     for tick in 0..max_ticks {
-        for tnt in &mut tnt_entities {
-            if tnt.fuse == tick {
-                pearl.data.motion += calculate_tnt_motion(pearl.data.position, tnt.data.position);
-            }
+        tick_tnt_entities(
+            &mut tnt_entities,
+            &mut pearl.data,
+            kind,
+            world_collisions,
+            M::VERSION,
+        );
+
+        if world_collisions.is_below_world(pearl.data.position.y) {
+            end_tick = tick + 1;
+            status = FlightStatus::RemovedInVoid;
+            break;
+        }
+
+        if let Some(chunk) = frozen_chunk(
+            pearl.data.position + world_offset,
+            profile.loads_own_chunk || (M::LOADS_OWN_CHUNK && kind.is_projectile()),
+            world_collisions,
+        ) {
+            end_tick = tick + 1;
+            status = FlightStatus::Frozen;
+            frozen = Some(FrozenResult {
+                tick: end_tick,
+                chunk,
+            });
+            break;
         }
 
-        M::run_tick_sequence(&mut pearl, world_collisions);
+        let hit = if kind.is_projectile() {
+            M::run_tick_sequence(&mut pearl, world_collisions)
+        } else {
+            profile.run_tick_sequence(&mut pearl.data, world_collisions)
+        };
+
+        if let Some(kind) = pearl.data.fluid {
+            fluid_ticks.push(FluidTick {
+                tick: tick + 1,
+                kind,
+            });
+        }
 
         traces.push_back(pearl.data.position);
         motion_traces.push_back(pearl.data.motion);
+
+        if let Some(hit) = hit {
+            end_tick = tick + 1;
+            status = FlightStatus::Landed;
+            hit_result = Some(HitResult {
+                tick: end_tick,
+                position: hit.position,
+                face: hit.face,
+            });
+            break;
+        }
     }
 
     let final_landing_pos = pearl.data.position;
+    let arrival = (kind == EntityKind::Pearl
+        && matches!(status, FlightStatus::InFlight | FlightStatus::Landed))
+    .then(|| {
+        super::arrival::analyze_arrival(
+            final_landing_pos,
+            world_collisions,
+            offset.unwrap_or_default(),
+        )
+    });
 
     let (distance_to_dest, is_success) = match destination {
         Some(dest) => {
             let distance = final_landing_pos.distance_2d(&dest);
-            (distance, distance <= 0.25)
+            (
+                distance,
+                distance <= 0.25 && matches!(status, FlightStatus::InFlight | FlightStatus::Landed),
+            )
         }
         None => (0.0, false),
     };
@@ -97,6 +184,9 @@ fn run_internal<M: PearlMovement + Clone>(
                 .into_iter()
                 .map(|pos| pos + offset_vec)
                 .collect();
+            if let Some(hit) = &mut hit_result {
+                hit.position += offset_vec;
+            }
             (final_pos, final_traces)
         }
         None => (final_landing_pos, final_traces),
@@ -107,23 +197,59 @@ fn run_internal<M: PearlMovement + Clone>(
         pearl_trace: final_traces_with_offset,
         pearl_motion_trace: final_motion_traces,
         is_successful: is_success,
-        tick: max_ticks,
+        tick: end_tick,
         final_motion: pearl.data.motion,
         distance: distance_to_dest,
+        hit: hit_result,
+        fluid_ticks,
+        status,
+        frozen,
+        arrival,
     })
 }
 
+/// Steps the flight and keeps the `valid_ticks` that end within reach of `destination`.
+/// With nothing in the world to hit, the valid ticks are first checked in closed form
+/// ([`PearlVersion::position_at_tick`]); only flights with a tick that may be in reach are
+/// stepped, through [`PearlVersion::free_flight_tick`] and only up to that tick.
 pub fn scan_trajectory(
     data: &GeneralData,
     destination: Space3D,
     max_tick: u32,
     valid_ticks: &[bool],
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     offset: Space3D,
     version: PearlVersion,
     max_distance_sq: f64,
     check_3d: bool,
 ) -> Vec<SimResult> {
+    if world_collisions.is_empty() && data.tnt_charges.is_empty() {
+        let Some(last_tick) = last_tick_in_reach(
+            data,
+            destination,
+            max_tick,
+            valid_ticks,
+            offset,
+            version,
+            max_distance_sq,
+            check_3d,
+        ) else {
+            return Vec::new();
+        };
+
+        return scan_free_flight(
+            data,
+            destination,
+            last_tick,
+            valid_ticks,
+            world_collisions,
+            offset,
+            version,
+            max_distance_sq,
+            check_3d,
+        );
+    }
+
     match version {
         PearlVersion::Legacy => scan_internal::<MovementLegacy>(
             data,
@@ -158,11 +284,94 @@ pub fn scan_trajectory(
     }
 }
 
+/// Latest of the `valid_ticks` whose closed-form position is within reach of `destination`,
+/// give or take [`PearlVersion::closed_form_slack`]. No later tick can be a hit, so stepping
+/// can stop there, and a flight without one needs no stepping at all.
+fn last_tick_in_reach(
+    data: &GeneralData,
+    destination: Space3D,
+    max_tick: u32,
+    valid_ticks: &[bool],
+    offset: Space3D,
+    version: PearlVersion,
+    max_distance_sq: f64,
+    check_3d: bool,
+) -> Option<u32> {
+    let max_distance = max_distance_sq.sqrt();
+    (1..=max_tick)
+        .rev()
+        .filter(|&tick| valid_ticks.get(tick as usize) == Some(&true))
+        .find(|&tick| {
+            let position =
+                version.position_at_tick(data.pearl_position, data.pearl_motion, tick) + offset;
+            let distance = if check_3d {
+                position.distance(&destination)
+            } else {
+                position.distance_2d(&destination)
+            };
+            let slack = version.closed_form_slack(data.pearl_position, data.pearl_motion, tick);
+            distance <= max_distance + slack
+        })
+}
+
+/// [`scan_internal`] for a world with nothing in it. It checks the same things in the same
+/// order and steps with [`PearlVersion::free_flight_tick`], so the results are identical
+/// while skipping raycasts, fluid lookups and entity bookkeeping. Without blocks or chunk
+/// loading the pearl can only be stopped by the void.
+fn scan_free_flight(
+    data: &GeneralData,
+    destination: Space3D,
+    max_tick: u32,
+    valid_ticks: &[bool],
+    world_collisions: &CollisionWorld,
+    offset: Space3D,
+    version: PearlVersion,
+    max_distance_sq: f64,
+    check_3d: bool,
+) -> Vec<SimResult> {
+    let mut results = Vec::new();
+    let mut position = data.pearl_position;
+    let mut motion = data.pearl_motion;
+
+    for tick in 1..=max_tick {
+        if world_collisions.is_below_world(position.y) {
+            break;
+        }
+
+        (position, motion) = version.free_flight_tick(position, motion);
+
+        if (tick as usize) < valid_ticks.len()
+            && valid_ticks[tick as usize]
+            && !world_collisions.is_below_world(position.y)
+        {
+            let current_pos = position + offset;
+            let dist_sq = if check_3d {
+                current_pos.distance_sq(&destination)
+            } else {
+                current_pos.distance_2d_sq(&destination)
+            };
+            if dist_sq <= max_distance_sq {
+                results.push(SimResult {
+                    tick,
+                    position: current_pos,
+                    motion,
+                    distance: dist_sq.sqrt(),
+                });
+            }
+        }
+
+        if motion.length_sq() < FLOAT_PRECISION_EPSILON {
+            break;
+        }
+    }
+    results
+}
+
 pub fn check_landing(
     data: &GeneralData,
     destination: Space3D,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     offset: Space3D,
     version: PearlVersion,
     max_distance_sq: f64,
@@ -200,7 +409,7 @@ fn scan_internal<M: PearlMovement + Clone>(
     destination: Space3D,
     max_tick: u32,
     valid_ticks: &[bool],
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     offset: Space3D,
     max_distance_sq: f64,
     check_3d: bool,
@@ -210,21 +419,38 @@ fn scan_internal<M: PearlMovement + Clone>(
     let mut tnt_entities: Vec<TNTEntity> = data
         .tnt_charges
         .iter()
-        .map(|tnt| TNTEntity::new(tnt.position, tnt.fuse))
+        .map(|tnt| TNTEntity::new(tnt.position, tnt.fuse, tnt.source))
         .collect();
 
     for tick in 1..=max_tick {
-        for tnt in &mut tnt_entities {
-            if tnt.fuse == tick - 1 {
-                pearl.data.motion += calculate_tnt_motion(pearl.data.position, tnt.data.position);
-            }
+        tick_tnt_entities(
+            &mut tnt_entities,
+            &mut pearl.data,
+            EntityKind::Pearl,
+            world_collisions,
+            M::VERSION,
+        );
+
+        if world_collisions.is_below_world(pearl.data.position.y)
+            || frozen_chunk(
+                pearl.data.position + offset,
+                M::LOADS_OWN_CHUNK,
+                world_collisions,
+            )
+            .is_some()
+        {
+            break;
         }
 
-        M::run_tick_sequence(&mut pearl, world_collisions);
+        let hit = M::run_tick_sequence(&mut pearl, world_collisions);
 
         let current_pos = pearl.data.position + offset;
 
-        if (tick as usize) < valid_ticks.len() && valid_ticks[tick as usize] {
+        // Nothing below the kill line can catch the pearl before it is discarded.
+        if (tick as usize) < valid_ticks.len()
+            && valid_ticks[tick as usize]
+            && !world_collisions.is_below_world(pearl.data.position.y)
+        {
             let dist_sq = if check_3d {
                 current_pos.distance_sq(&destination)
             } else {
@@ -240,6 +466,10 @@ fn scan_internal<M: PearlMovement + Clone>(
             }
         }
 
+        if hit.is_some() {
+            break;
+        }
+
         if pearl.data.motion.length_sq() < FLOAT_PRECISION_EPSILON {
             let dist_sq = if check_3d {
                 current_pos.distance_sq(&destination)
@@ -257,7 +487,7 @@ fn check_internal<M: PearlMovement + Clone>(
     data: &GeneralData,
     destination: Space3D,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     offset: Space3D,
     max_distance_sq: f64,
 ) -> Option<(Space3D, Space3D, u32)> {
@@ -265,17 +495,35 @@ fn check_internal<M: PearlMovement + Clone>(
     let mut tnt_entities: Vec<TNTEntity> = data
         .tnt_charges
         .iter()
-        .map(|tnt| TNTEntity::new(tnt.position, tnt.fuse))
+        .map(|tnt| TNTEntity::new(tnt.position, tnt.fuse, tnt.source))
         .collect();
 
+    let mut end_tick = max_ticks;
+
     for tick in 0..max_ticks {
-        for tnt in &mut tnt_entities {
-            if tnt.fuse == tick {
-                pearl.data.motion += calculate_tnt_motion(pearl.data.position, tnt.data.position);
-            }
+        tick_tnt_entities(
+            &mut tnt_entities,
+            &mut pearl.data,
+            EntityKind::Pearl,
+            world_collisions,
+            M::VERSION,
+        );
+
+        if world_collisions.is_below_world(pearl.data.position.y)
+            || frozen_chunk(
+                pearl.data.position + offset,
+                M::LOADS_OWN_CHUNK,
+                world_collisions,
+            )
+            .is_some()
+        {
+            return None;
         }
 
-        M::run_tick_sequence(&mut pearl, world_collisions);
+        if M::run_tick_sequence(&mut pearl, world_collisions).is_some() {
+            end_tick = tick + 1;
+            break;
+        }
 
         if pearl.data.motion.length_sq() < FLOAT_PRECISION_EPSILON {
             break;
@@ -285,26 +533,166 @@ fn check_internal<M: PearlMovement + Clone>(
     let final_pos = pearl.data.position + offset;
 
     if final_pos.distance_2d_sq(&destination) <= max_distance_sq {
-        Some((final_pos, pearl.data.motion, max_ticks))
+        Some((final_pos, pearl.data.motion, end_tick))
     } else {
         None
     }
 }
 
-pub fn calculate_tnt_motion(pearl_pos: Space3D, tnt_pos: Space3D) -> Space3D {
-    let mut tnt_pos_adjusted = tnt_pos;
-    tnt_pos_adjusted.y += TNT_ENTITY_Y_OFFSET;
+/// Chunk the pearl is stuck in when it starts a tick outside the entity-ticking area.
+/// `position` is in world coordinates, since loaded chunks are.
+fn frozen_chunk(
+    position: Space3D,
+    loads_own_chunk: bool,
+    world_collisions: &CollisionWorld,
+) -> Option<ChunkPos> {
+    if loads_own_chunk {
+        return None;
+    }
+    let chunk = ChunkPos::from_position(position);
+    let chunk_loading = world_collisions.chunk_loading()?;
+    (!chunk_loading.is_entity_ticking(chunk)).then_some(chunk)
+}
 
-    let distance_vec = pearl_pos - tnt_pos_adjusted;
+/// Ticks the TNT ahead of the pearl, like entities that were loaded first. A TNT explodes in
+/// the tick that takes its fuse to 0, after moving, as `PrimedTnt` does; one spawned with no
+/// fuse explodes where it is before anything moves.
+fn tick_tnt_entities(
+    tnt_entities: &mut Vec<TNTEntity>,
+    pearl: &mut EntityData,
+    kind: EntityKind,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) {
+    let mut index = 0;
+    while index < tnt_entities.len() {
+        if tnt_entities[index].fuse > 0 {
+            tnt_entities[index].tick(world_collisions);
+            if tnt_entities[index].fuse > 0 {
+                index += 1;
+                continue;
+            }
+        }
+
+        let exploded = tnt_entities.remove(index);
+        pearl.motion += calculate_explosion_motion(
+            pearl.position,
+            exploded.data.position,
+            exploded.source,
+            kind,
+            version,
+            world_collisions,
+        );
+
+        for other in tnt_entities
+            .iter_mut()
+            .filter(|other| other.source.is_mobile())
+        {
+            other.data.motion += calculate_explosion_motion_on_tnt(
+                &other.data,
+                exploded.data.position,
+                exploded.source,
+                version,
+                world_collisions,
+            );
+        }
+    }
+}
+
+/// TNT knockback is the same in every version.
+pub fn calculate_tnt_motion(
+    entity_pos: Space3D,
+    tnt_pos: Space3D,
+    kind: EntityKind,
+    world_collisions: &CollisionWorld,
+) -> Space3D {
+    calculate_explosion_motion(
+        entity_pos,
+        tnt_pos,
+        ExplosionSource::Tnt,
+        kind,
+        PearlVersion::Post1212,
+        world_collisions,
+    )
+}
+
+pub fn calculate_explosion_motion(
+    entity_pos: Space3D,
+    source_pos: Space3D,
+    source: ExplosionSource,
+    kind: EntityKind,
+    version: PearlVersion,
+    world_collisions: &CollisionWorld,
+) -> Space3D {
+    let profile = kind.profile();
+    let entity_box = profile.bounding_box(entity_pos);
+
+    calculate_explosion_knockback(
+        entity_pos,
+        entity_pos.y + profile.knockback_height,
+        &entity_box,
+        source_pos,
+        source,
+        version,
+        world_collisions,
+    )
+}
+
+/// Primed TNT is pushed along the line from the explosion to its feet rather than its eyes.
+pub fn calculate_tnt_motion_on_tnt(
+    target: &EntityData,
+    tnt_pos: Space3D,
+    world_collisions: &CollisionWorld,
+) -> Space3D {
+    calculate_explosion_motion_on_tnt(
+        target,
+        tnt_pos,
+        ExplosionSource::Tnt,
+        PearlVersion::Post1212,
+        world_collisions,
+    )
+}
+
+pub fn calculate_explosion_motion_on_tnt(
+    target: &EntityData,
+    source_pos: Space3D,
+    source: ExplosionSource,
+    version: PearlVersion,
+    world_collisions: &CollisionWorld,
+) -> Space3D {
+    calculate_explosion_knockback(
+        target.position,
+        target.position.y,
+        &target.bounding_box,
+        source_pos,
+        source,
+        version,
+        world_collisions,
+    )
+}
+
+fn calculate_explosion_knockback(
+    target_pos: Space3D,
+    target_knockback_y: f64,
+    target_box: &AABBBox,
+    source_pos: Space3D,
+    source: ExplosionSource,
+    version: PearlVersion,
+    world_collisions: &CollisionWorld,
+) -> Space3D {
+    let center = source.center(source_pos);
+    let radius = source.knockback_radius();
+
+    let distance_vec = target_pos - center;
     let distance_scalar = distance_vec.length();
 
-    if distance_scalar >= TNT_EXPLOSION_RADIUS {
+    if distance_scalar >= radius {
         return Space3D::default();
     }
 
     let mut explosion_vec = Space3D::new(
         distance_vec.x,
-        pearl_pos.y + (PEARL_EXPLOSION_Y_FACTOR * PEARL_HEIGHT) - tnt_pos_adjusted.y,
+        target_knockback_y - center.y,
         distance_vec.z,
     );
 
@@ -314,7 +702,10 @@ pub fn calculate_tnt_motion(pearl_pos: Space3D, tnt_pos: Space3D) -> Space3D {
     }
     explosion_vec /= explosion_vec_len;
 
-    let explosion_strength = 1.0 - (distance_scalar / TNT_EXPLOSION_RADIUS);
+    let seen_percent = exposure::calculate_seen_percent(center, target_box, world_collisions);
+
+    let explosion_strength =
+        (1.0 - (distance_scalar / radius)) * seen_percent * source.knockback_multiplier(version);
 
     explosion_vec * explosion_strength
 }
@@ -328,3 +719,258 @@ impl DistanceSqExt for Space3D {
         (self.x - other.x).powi(2) + (self.z - other.z).powi(2)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::inputs::TNT;
+    use crate::physics::world::dimension::Dimension;
+
+    /// The full entity path, bypassing the free-flight shortcut in [`scan_trajectory`].
+    fn scan_stepped(
+        data: &GeneralData,
+        max_tick: u32,
+        valid_ticks: &[bool],
+        world_collisions: &CollisionWorld,
+        version: PearlVersion,
+    ) -> Vec<SimResult> {
+        let (destination, offset) = (Space3D::default(), Space3D::default());
+        match version {
+            PearlVersion::Legacy => scan_internal::<MovementLegacy>(
+                data,
+                destination,
+                max_tick,
+                valid_ticks,
+                world_collisions,
+                offset,
+                f64::MAX,
+                true,
+            ),
+            PearlVersion::Post1205 => scan_internal::<MovementPost1205>(
+                data,
+                destination,
+                max_tick,
+                valid_ticks,
+                world_collisions,
+                offset,
+                f64::MAX,
+                true,
+            ),
+            PearlVersion::Post1212 => scan_internal::<MovementPost1212>(
+                data,
+                destination,
+                max_tick,
+                valid_ticks,
+                world_collisions,
+                offset,
+                f64::MAX,
+                true,
+            ),
+        }
+    }
+
+    #[test]
+    fn free_flight_matches_stepping_bit_for_bit() {
+        let max_tick = 2000;
+        let valid_ticks = vec![true; max_tick as usize + 1];
+        let worlds = [
+            CollisionWorld::default(),
+            CollisionWorld::default().with_dimension(Dimension::Overworld.settings()),
+        ];
+        let speeds = [0.0, 0.37, 1.5, 12.8, 95.3, 1234.5];
+
+        for world in &worlds {
+            assert!(world.is_empty());
+            for version in [
+                PearlVersion::Legacy,
+                PearlVersion::Post1205,
+                PearlVersion::Post1212,
+            ] {
+                for &x in &speeds {
+                    for &y in &[-3.1, -0.2, 0.0, 0.61, 7.25, 80.0] {
+                        for &z in &speeds {
+                            let data = GeneralData {
+                                pearl_position: Space3D::new(0.3125, 170.34, -0.71),
+                                pearl_motion: Space3D::new(x, y, -z),
+                                tnt_charges: Vec::new(),
+                            };
+                            let stepped =
+                                scan_stepped(&data, max_tick, &valid_ticks, world, version);
+                            let free = scan_free_flight(
+                                &data,
+                                Space3D::default(),
+                                max_tick,
+                                &valid_ticks,
+                                world,
+                                Space3D::default(),
+                                version,
+                                f64::MAX,
+                                true,
+                            );
+
+                            assert_eq!(stepped.len(), free.len(), "{version:?} {x} {y} {z}");
+                            for (a, b) in stepped.iter().zip(&free) {
+                                assert_eq!(a.tick, b.tick);
+                                assert_eq!(a.position, b.position, "{version:?} tick {}", a.tick);
+                                assert_eq!(a.motion, b.motion, "{version:?} tick {}", a.tick);
+                                assert_eq!(a.distance.to_bits(), b.distance.to_bits());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn closed_form_stays_within_its_slack() {
+        let start = Space3D::new(-29_999_000.3, 170.34, 12_345.71);
+        for version in [
+            PearlVersion::Legacy,
+            PearlVersion::Post1205,
+            PearlVersion::Post1212,
+        ] {
+            for motion in [
+                Space3D::new(0.0, 0.0, 0.0),
+                Space3D::new(0.37, -3.1, 1.5),
+                Space3D::new(95.3, 7.25, -12.8),
+                Space3D::new(-1234.5, 80.0, 1234.5),
+            ] {
+                let (mut position, mut stepped_motion) = (start, motion);
+                for tick in 1..=2000 {
+                    (position, stepped_motion) = version.free_flight_tick(position, stepped_motion);
+                    let error = version
+                        .position_at_tick(start, motion, tick)
+                        .distance(&position);
+                    let slack = version.closed_form_slack(start, motion, tick);
+                    assert!(error <= slack, "{version:?} tick {tick}: {error} > {slack}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn closed_form_filter_keeps_every_hit() {
+        let max_tick = 600;
+        let valid_ticks: Vec<bool> = (0..=max_tick).map(|tick| tick % 3 != 1).collect();
+        let world = CollisionWorld::default();
+        let offset = Space3D::new(1000.5, 0.0, -2000.5);
+
+        for version in [
+            PearlVersion::Legacy,
+            PearlVersion::Post1205,
+            PearlVersion::Post1212,
+        ] {
+            for motion in [Space3D::new(0.4, 1.2, -2.5), Space3D::new(-41.0, 3.0, 17.3)] {
+                let data = GeneralData {
+                    pearl_position: Space3D::new(0.5, 120.0, 0.5),
+                    pearl_motion: motion,
+                    tnt_charges: Vec::new(),
+                };
+                let mut all = scan_stepped(&data, max_tick, &valid_ticks, &world, version);
+                for hit in &mut all {
+                    hit.position += offset;
+                }
+
+                for (target_tick, max_distance) in [(299, 0.0), (300, 3.0), (450, 40.0)] {
+                    let mut destination = all
+                        .iter()
+                        .find(|hit| hit.tick == target_tick)
+                        .unwrap()
+                        .position;
+                    destination.x += max_distance;
+                    let max_distance_sq = max_distance * max_distance;
+
+                    for check_3d in [false, true] {
+                        let expected: Vec<(u32, Space3D)> = all
+                            .iter()
+                            .filter(|hit| {
+                                let dist_sq = if check_3d {
+                                    hit.position.distance_sq(&destination)
+                                } else {
+                                    hit.position.distance_2d_sq(&destination)
+                                };
+                                dist_sq <= max_distance_sq
+                            })
+                            .map(|hit| (hit.tick, hit.position))
+                            .collect();
+                        let filtered: Vec<(u32, Space3D)> = scan_trajectory(
+                            &data,
+                            destination,
+                            max_tick,
+                            &valid_ticks,
+                            &world,
+                            offset,
+                            version,
+                            max_distance_sq,
+                            check_3d,
+                        )
+                        .into_iter()
+                        .map(|hit| (hit.tick, hit.position))
+                        .collect();
+
+                        assert!(!expected.is_empty());
+                        assert_eq!(expected, filtered, "{version:?} tick {target_tick}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fused_tnt_explodes_in_the_tick_its_fuse_runs_out() {
+        let version = PearlVersion::Post1212;
+        let world = CollisionWorld::default();
+        let fuse = 4;
+        let tnt_start = Space3D::new(0.5, 100.0, -1.5);
+        let data = GeneralData {
+            pearl_position: Space3D::new(0.5, 100.0, 0.5),
+            pearl_motion: Space3D::default(),
+            tnt_charges: vec![TNT {
+                position: tnt_start,
+                fuse,
+                source: ExplosionSource::Tnt,
+            }],
+        };
+        let boosted = run(&data, EntityKind::Pearl, None, 10, &world, None, version).unwrap();
+        let unboosted = run(
+            &GeneralData {
+                tnt_charges: Vec::new(),
+                ..data.clone()
+            },
+            EntityKind::Pearl,
+            None,
+            10,
+            &world,
+            None,
+            version,
+        )
+        .unwrap();
+
+        // The TNT falls for `fuse` ticks and explodes before the pearl's tick `fuse` moves.
+        let mut tnt = TNTEntity::new(tnt_start, fuse, ExplosionSource::Tnt);
+        for _ in 0..fuse {
+            tnt.tick(&world);
+        }
+        assert_eq!(tnt.data.position.y, 99.60792031999999);
+
+        let before = fuse as usize - 1;
+        assert_eq!(
+            boosted.pearl_trace[..=before],
+            unboosted.pearl_trace[..=before]
+        );
+        let pearl_position = boosted.pearl_trace[before];
+        let kick = calculate_explosion_motion(
+            pearl_position,
+            tnt.data.position,
+            ExplosionSource::Tnt,
+            EntityKind::Pearl,
+            version,
+            &world,
+        );
+        let (position, motion) =
+            version.free_flight_tick(pearl_position, boosted.pearl_motion_trace[before] + kick);
+        assert_eq!(boosted.pearl_trace[before + 1], position);
+        assert_eq!(boosted.pearl_motion_trace[before + 1], motion);
+    }
+}
@@ -60,8 +60,9 @@ pub fn solve_theoretical_tnt(input: &SolverInput) -> HashMap<(i32, i32, i32), Ve
             let true_red = (compensated_distance.z * input.blue_vec.x
                 - compensated_distance.x * input.blue_vec.z)
                 / denominator;
-            let true_blue =
-                (compensated_distance.x - true_red * input.red_vec.x) / input.blue_vec.x;
+            let true_blue = (compensated_distance.x * input.red_vec.z
+                - compensated_distance.z * input.red_vec.x)
+                / denominator;
 
             let ideal_red = (true_red / divider).round() as i32;
             let ideal_blue = (true_blue / divider).round() as i32;
@@ -96,3 +97,245 @@ fn solve_tnt_system_3d(
 
     Some((dr / det, db / det, dv / det))
 }
+
+pub struct GroupSolverInput {
+    pub group_vecs: Vec<Space3D>,
+    pub max_counts: Vec<u32>,
+    pub is_3d_solve: bool,
+    pub start_pos: Space3D,
+    pub destination: Space3D,
+    pub max_ticks: u32,
+    pub version: PearlVersion,
+}
+
+/// Returns `true` when the group vectors span all three axes, i.e. the
+/// vertical component can be solved for instead of left to gravity.
+pub fn groups_span_3d(group_vecs: &[Space3D]) -> bool {
+    for (i, &a) in group_vecs.iter().enumerate() {
+        for (j, &b) in group_vecs.iter().enumerate().skip(i + 1) {
+            for &c in group_vecs.iter().skip(j + 1) {
+                if a.dot(b.cross(c)).abs() > FLOAT_PRECISION_EPSILON {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+pub fn solve_theoretical_groups(input: &GroupSolverInput) -> HashMap<Vec<u32>, Vec<u32>> {
+    let true_distance = input.destination - input.start_pos;
+
+    let mut groups: HashMap<Vec<u32>, Vec<u32>> = HashMap::new();
+    if input.group_vecs.is_empty() {
+        return groups;
+    }
+
+    let drag_multiplier = PEARL_DRAG_MULTIPLIER;
+    let denominator_constant = 1.0 - drag_multiplier;
+    let is_3d_solve = input.is_3d_solve;
+
+    // A 2D system leaves the vertical axis to gravity, so only x/z take part
+    // in the fit, matching the red/blue solver.
+    let columns: Vec<Space3D> = input
+        .group_vecs
+        .iter()
+        .map(|&v| {
+            if is_3d_solve {
+                v
+            } else {
+                Space3D::new(v.x, 0.0, v.z)
+            }
+        })
+        .collect();
+
+    let gravity = -crate::physics::constants::constants::PEARL_GRAVITY_ACCELERATION;
+    let mut sim_grav_vel = 0.0;
+    let mut sim_grav_pos = 0.0;
+
+    for tick in 1..=input.max_ticks {
+        sim_grav_vel = input
+            .version
+            .apply_grav_drag_tick(sim_grav_vel, gravity, drag_multiplier);
+        sim_grav_pos += sim_grav_vel;
+
+        let mut compensated_distance = true_distance;
+        compensated_distance.y -= sim_grav_pos;
+        if !is_3d_solve {
+            compensated_distance.y = 0.0;
+        }
+
+        let numerator = 1.0 - drag_multiplier.powi(tick as i32);
+        let divider = input.version.get_projection_multiplier(drag_multiplier) * numerator
+            / denominator_constant;
+        let target_motion = compensated_distance / divider;
+
+        if let Some(continuous) =
+            solve_bounded_least_squares(&columns, target_motion, &input.max_counts)
+        {
+            let counts =
+                refine_integer_counts(&columns, target_motion, &continuous, &input.max_counts);
+            groups.entry(counts).or_default().push(tick);
+        }
+    }
+
+    groups
+}
+
+/// Least-squares fit of non-negative counts, clamping any group that leaves
+/// `[0, max_count]` to its bound and re-fitting the rest.
+fn solve_bounded_least_squares(
+    columns: &[Space3D],
+    target: Space3D,
+    max_counts: &[u32],
+) -> Option<Vec<f64>> {
+    let mut fixed: Vec<Option<f64>> = vec![None; columns.len()];
+
+    loop {
+        let free: Vec<usize> = (0..columns.len()).filter(|&i| fixed[i].is_none()).collect();
+        let residual_target = fixed
+            .iter()
+            .zip(columns)
+            .filter_map(|(value, &column)| value.map(|v| column * v))
+            .fold(target, |acc, impact| acc - impact);
+
+        let free_columns: Vec<Space3D> = free.iter().map(|&i| columns[i]).collect();
+        let free_solution = solve_least_squares(&free_columns, residual_target)?;
+
+        let mut clamped_any = false;
+        for (&i, &value) in free.iter().zip(&free_solution) {
+            let upper = max_counts.get(i).copied().unwrap_or(0);
+            if value < 0.0 {
+                fixed[i] = Some(0.0);
+                clamped_any = true;
+            } else if upper > 0 && value > upper as f64 {
+                fixed[i] = Some(upper as f64);
+                clamped_any = true;
+            }
+        }
+
+        if !clamped_any {
+            let mut solution: Vec<f64> = fixed.iter().map(|v| v.unwrap_or(0.0)).collect();
+            for (&i, &value) in free.iter().zip(&free_solution) {
+                solution[i] = value;
+            }
+            return Some(solution);
+        }
+    }
+}
+
+/// Solves the normal equations `(AᵀA + λI)x = Aᵀb`. The tiny ridge term
+/// keeps under-determined systems solvable and steers them towards the
+/// minimum-norm solution; over-determined systems get the ordinary fit.
+fn solve_least_squares(columns: &[Space3D], target: Space3D) -> Option<Vec<f64>> {
+    let n = columns.len();
+    if n == 0 {
+        return Some(Vec::new());
+    }
+
+    let mut matrix = vec![vec![0.0; n + 1]; n];
+    for i in 0..n {
+        for j in 0..n {
+            matrix[i][j] = columns[i].dot(columns[j]);
+        }
+        matrix[i][n] = columns[i].dot(target);
+    }
+
+    let max_diagonal = (0..n).map(|i| matrix[i][i]).fold(0.0, f64::max);
+    if max_diagonal < FLOAT_PRECISION_EPSILON {
+        return None;
+    }
+    let ridge = max_diagonal * 1e-9;
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[i] += ridge;
+    }
+
+    for col in 0..n {
+        let pivot =
+            (col..n).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        // Also rejects NaN, which `total_cmp` ranks above every number.
+        if !matrix[pivot][col].is_normal() {
+            return None;
+        }
+        matrix.swap(col, pivot);
+
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower.iter_mut() {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = ((row + 1)..n).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (matrix[row][n] - tail) / matrix[row][row];
+    }
+
+    Some(solution)
+}
+
+/// Rounds the continuous fit and then walks ±1 steps on single groups while
+/// that lowers the motion residual, since plain rounding is not the best
+/// integer point once groups pull in similar directions.
+fn refine_integer_counts(
+    columns: &[Space3D],
+    target: Space3D,
+    continuous: &[f64],
+    max_counts: &[u32],
+) -> Vec<u32> {
+    // A `max_count` of 0 leaves the group unbounded, as far as a count can go.
+    let upper_bound = |i: usize| match max_counts.get(i).copied().unwrap_or(0) {
+        0 => u32::MAX as i64,
+        max => max as i64,
+    };
+    let residual = |counts: &[i64]| {
+        let motion = counts
+            .iter()
+            .zip(columns)
+            .fold(Space3D::default(), |acc, (&count, &column)| {
+                acc + column * (count as f64)
+            });
+        (target - motion).length_sq()
+    };
+
+    let mut counts: Vec<i64> = continuous
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| (value.round() as i64).clamp(0, upper_bound(i)))
+        .collect();
+    let mut current = residual(&counts);
+
+    loop {
+        let mut best_step: Option<(usize, i64, f64)> = None;
+        for i in 0..counts.len() {
+            for step in [-1, 1] {
+                let next = counts[i] + step;
+                if next < 0 || next > upper_bound(i) {
+                    continue;
+                }
+                counts[i] = next;
+                let candidate = residual(&counts);
+                counts[i] -= step;
+
+                let best = best_step.map_or(current, |(_, _, r)| r);
+                if candidate < best - FLOAT_PRECISION_EPSILON {
+                    best_step = Some((i, step, candidate));
+                }
+            }
+        }
+
+        match best_step {
+            Some((i, step, candidate)) => {
+                counts[i] += step;
+                current = candidate;
+            }
+            None => break,
+        }
+    }
+
+    counts.into_iter().map(|c| c as u32).collect()
+}
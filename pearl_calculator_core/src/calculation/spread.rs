@@ -0,0 +1,272 @@
+use crate::calculation::inputs::{Cannon, GeneralData, SpreadParams};
+use crate::calculation::results::{ConfidenceEllipse, FlightStatus, LandingDistribution};
+use crate::calculation::simulation;
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::constants::constants::{
+    CONFIDENCE_95_CHI_SQUARED_2D, TNT_IGNITION_KICK, TNT_IGNITION_LIFT,
+};
+use crate::physics::entities::entities::EntityTrait;
+use crate::physics::entities::entity_kind::EntityKind;
+use crate::physics::entities::movement::PearlVersion;
+use crate::physics::entities::tnt_entities::TNTEntity;
+use crate::physics::explosion::source::ExplosionSource;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::direction::Direction;
+use crate::physics::world::space::Space3D;
+use crate::utils::java_random::JavaRandom;
+use std::f64::consts::PI;
+
+/// Replays the shot `params.samples` times, each TNT exploding where its random ignition
+/// kick (and spawn spread) carried it instead of at its configured position. Every sample
+/// flies until it lands itself; one that never does is taken at the nominal shot's tick.
+pub fn landing_distribution(
+    cannon: &Cannon,
+    red_tnt: u32,
+    blue_tnt: u32,
+    vertical_tnt: u32,
+    direction: Direction,
+    destination: Space3D,
+    params: &SpreadParams,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<LandingDistribution> {
+    if params.samples == 0 {
+        return None;
+    }
+
+    let nominal = super::trace::calculate_pearl_trace(
+        cannon,
+        red_tnt,
+        blue_tnt,
+        vertical_tnt,
+        direction,
+        EntityKind::Pearl,
+        max_ticks,
+        world_collisions,
+        version,
+    )?;
+    let tick = if nominal.status == FlightStatus::Landed {
+        nominal.tick
+    } else {
+        nominal
+            .pearl_trace
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                a.distance_2d(&destination)
+                    .total_cmp(&b.distance_2d(&destination))
+            })
+            .map_or(0, |(index, _)| index as u32)
+    };
+
+    let pearl_calc_pos = super::vectors::pearl_calc_position(cannon);
+    let (red_tnt_loc, blue_tnt_loc) = super::vectors::resolve_tnt_positions(cannon, direction)?;
+    let mut charges = vec![(red_tnt_loc, red_tnt), (blue_tnt_loc, blue_tnt)];
+    if let Some(vertical_tnt_loc) = cannon.vertical_tnt {
+        charges.push((vertical_tnt_loc, vertical_tnt));
+    }
+
+    let drift = ignition_drift(params.fuse);
+    let mut random = JavaRandom::new(params.seed);
+
+    let mut landings = Vec::with_capacity(params.samples as usize);
+    for _ in 0..params.samples {
+        let mut motion = cannon.pearl.motion;
+        for &(position, count) in &charges {
+            for _ in 0..count {
+                let tnt_position = position + sample_displacement(&mut random, drift, params);
+                motion += simulation::calculate_tnt_motion(
+                    pearl_calc_pos,
+                    tnt_position,
+                    EntityKind::Pearl,
+                    world_collisions,
+                );
+            }
+        }
+
+        let data = GeneralData {
+            pearl_position: cannon.pearl.position,
+            pearl_motion: motion,
+            tnt_charges: Vec::new(),
+        };
+        let result = simulation::run(
+            &data,
+            EntityKind::Pearl,
+            None,
+            max_ticks,
+            world_collisions,
+            Some(cannon.pearl.offset),
+            version,
+        )?;
+        let landing = if result.status == FlightStatus::Landed {
+            result.landing_position
+        } else {
+            result
+                .pearl_trace
+                .get(tick as usize)
+                .copied()
+                .unwrap_or(result.landing_position)
+        };
+        landings.push(landing);
+    }
+
+    Some(summarize(tick, landings, destination, params.hit_radius))
+}
+
+/// How far the ignition kick carries a TNT along a flat floor before it explodes. Only the
+/// direction of the kick is random, so one run covers every TNT.
+fn ignition_drift(fuse: u32) -> f64 {
+    let floor = CollisionWorld::new(vec![AABBBox::new(-8.0, -1.0, -8.0, 8.0, 0.0, 8.0)]);
+    let mut tnt = TNTEntity::new(Space3D::default(), fuse, ExplosionSource::Tnt);
+    tnt.data.motion = Space3D::new(0.0, TNT_IGNITION_LIFT, TNT_IGNITION_KICK);
+
+    for _ in 0..fuse {
+        tnt.tick(&floor);
+    }
+
+    tnt.data.position.z
+}
+
+/// Draws the kick angle like `PrimedTnt` does, then the spawn spread if there is one.
+fn sample_displacement(random: &mut JavaRandom, drift: f64, params: &SpreadParams) -> Space3D {
+    let angle = random.next_double() * (PI * 2.0) as f32 as f64;
+    let mut displacement = Space3D::new(-angle.sin() * drift, 0.0, -angle.cos() * drift);
+
+    if params.spawn_spread > 0.0 {
+        displacement.x += random.next_gaussian() * params.spawn_spread;
+        displacement.z += random.next_gaussian() * params.spawn_spread;
+    }
+
+    displacement
+}
+
+fn summarize(
+    tick: u32,
+    landings: Vec<Space3D>,
+    destination: Space3D,
+    hit_radius: f64,
+) -> LandingDistribution {
+    let count = landings.len() as f64;
+    let mean = landings
+        .iter()
+        .fold(Space3D::default(), |sum, &landing| sum + landing)
+        / count;
+
+    let degrees_of_freedom = (count - 1.0).max(1.0);
+    let (mut xx, mut xz, mut zz) = (0.0, 0.0, 0.0);
+    for landing in &landings {
+        let dx = landing.x - mean.x;
+        let dz = landing.z - mean.z;
+        xx += dx * dx;
+        xz += dx * dz;
+        zz += dz * dz;
+    }
+    let covariance = [
+        [xx / degrees_of_freedom, xz / degrees_of_freedom],
+        [xz / degrees_of_freedom, zz / degrees_of_freedom],
+    ];
+
+    let hits = landings
+        .iter()
+        .filter(|landing| landing.distance_2d(&destination) <= hit_radius)
+        .count();
+
+    LandingDistribution {
+        tick,
+        landings,
+        mean,
+        covariance,
+        ellipse: confidence_ellipse(covariance),
+        hit_probability: hits as f64 / count,
+    }
+}
+
+fn confidence_ellipse(covariance: [[f64; 2]; 2]) -> ConfidenceEllipse {
+    let [[xx, xz], [_, zz]] = covariance;
+    let half_trace = (xx + zz) / 2.0;
+    let radius = (((xx - zz) / 2.0).powi(2) + xz * xz).sqrt();
+    let major = (half_trace + radius).max(0.0);
+    let minor = (half_trace - radius).max(0.0);
+
+    ConfidenceEllipse {
+        semi_major: (major * CONFIDENCE_95_CHI_SQUARED_2D).sqrt(),
+        semi_minor: (minor * CONFIDENCE_95_CHI_SQUARED_2D).sqrt(),
+        angle: (0.5 * (2.0 * xz).atan2(xx - zz)).to_degrees(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculation::inputs::Pearl;
+    use crate::physics::constants::constants::TNT_DEFAULT_FUSE;
+    use crate::physics::world::dimension::Dimension;
+    use crate::physics::world::layout_direction::LayoutDirection;
+    use crate::settings::CannonMode;
+
+    fn cannon() -> Cannon {
+        let tnt = |x: f64, z: f64| Space3D::new(x, 60.0, z);
+        Cannon {
+            pearl: Pearl {
+                position: Space3D::new(0.5, 60.34, 0.5),
+                motion: Space3D::default(),
+                offset: Space3D::default(),
+            },
+            red_tnt_override: None,
+            blue_tnt_override: None,
+            vertical_tnt: None,
+            mode: CannonMode::Standard,
+            north_west_tnt: tnt(-0.884999, -0.884999),
+            north_east_tnt: tnt(1.884999, -0.884999),
+            south_west_tnt: tnt(-0.884999, 1.884999),
+            south_east_tnt: tnt(1.884999, 1.884999),
+            north_tnt: tnt(0.5, -0.884999),
+            south_tnt: tnt(0.5, 1.884999),
+            west_tnt: tnt(-0.884999, 0.5),
+            east_tnt: tnt(1.884999, 0.5),
+            default_red_duper: Some(LayoutDirection::SouthWest),
+            default_blue_duper: Some(LayoutDirection::SouthEast),
+            dimension: Dimension::Overworld,
+        }
+    }
+
+    #[test]
+    fn samples_land_on_their_own_tick() {
+        let floor = AABBBox::new(-200.0, 50.0, -200.0, 200.0, 51.0, 200.0);
+        let world =
+            CollisionWorld::new(vec![floor]).with_dimension(Dimension::Overworld.settings());
+        let params = SpreadParams {
+            samples: 4,
+            seed: 13,
+            fuse: TNT_DEFAULT_FUSE,
+            spawn_spread: 0.1,
+            hit_radius: 0.5,
+        };
+        let distribution = landing_distribution(
+            &cannon(),
+            3,
+            2,
+            0,
+            Direction::North,
+            Space3D::new(0.0, 51.0, -120.0),
+            &params,
+            200,
+            &world,
+            PearlVersion::Post1212,
+        )
+        .unwrap();
+
+        assert_eq!(distribution.tick, 58);
+        // Every sample lands after the nominal shot does, so none may be cut off at its tick.
+        assert_eq!(
+            distribution.landings,
+            [
+                Space3D::new(-9.610186408423413, 51.0, -128.87232019801596),
+                Space3D::new(2.244971379086021, 51.0, -123.78708048926799),
+                Space3D::new(4.9029595088864095, 51.0, -131.5663717692635),
+                Space3D::new(0.24042014350140606, 51.0, -126.7217576242808),
+            ]
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use crate::calculation::inputs::{GeneralData, Thrower};
+use crate::calculation::results::{ThrowArc, ThrowResult};
+use crate::calculation::simulation;
+use crate::physics::constants::constants::{
+    F3_ANGLE_DECIMALS, FLOAT_PRECISION_EPSILON, THROWN_PEARL_EYE_OFFSET, THROWN_PEARL_SPEED,
+};
+use crate::physics::entities::entity_kind::EntityKind;
+use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::space::Space3D;
+use crate::utils::utils;
+use std::f64::consts::PI;
+
+const PITCH_SCAN_STEP: f64 = 0.5;
+
+const BISECTION_STEPS: u32 = 40;
+
+/// Pearl position and motion right after a hand throw, as `shootFromRotation` computes them
+/// without the random spread. Angles are `f32` like the player's rotation, and the thrower's
+/// motion is added the same way in every version.
+pub fn throw_state(thrower: &Thrower, yaw: f32, pitch: f32) -> (Space3D, Space3D) {
+    let to_radians = (PI / 180.0) as f32;
+    let yaw = yaw * to_radians;
+    let pitch = pitch * to_radians;
+
+    let direction = Space3D::new(
+        (-utils::mth_sin(yaw) * utils::mth_cos(pitch)) as f64,
+        (-utils::mth_sin(pitch)) as f64,
+        (utils::mth_cos(yaw) * utils::mth_cos(pitch)) as f64,
+    );
+    let mut motion = direction / direction.length() * THROWN_PEARL_SPEED;
+    motion.x += thrower.motion.x;
+    motion.z += thrower.motion.z;
+    if !thrower.on_ground {
+        motion.y += thrower.motion.y;
+    }
+
+    let mut position = thrower.eye_position;
+    position.y -= THROWN_PEARL_EYE_OFFSET;
+
+    (position, motion)
+}
+
+/// Finds the low and high arc onto `destination`. Pitches are searched collision-free, then
+/// the nearest F3-representable angles are checked with [`simulation::run`] and the closest
+/// one is kept for each arc.
+pub fn solve_throw(
+    thrower: &Thrower,
+    destination: Space3D,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Vec<ThrowResult> {
+    let horizontal = Space3D::new(
+        destination.x - thrower.eye_position.x,
+        0.0,
+        destination.z - thrower.eye_position.z,
+    );
+    let horizontal_distance = horizontal.length();
+    if horizontal_distance < FLOAT_PRECISION_EPSILON {
+        return Vec::new();
+    }
+    let target_direction = horizontal / horizontal_distance;
+
+    let height_error = |pitch: f64| {
+        height_error(
+            thrower,
+            target_direction,
+            destination,
+            pitch,
+            max_ticks,
+            world_collisions,
+            version,
+        )
+    };
+
+    let sample_count = (180.0 / PITCH_SCAN_STEP) as i32;
+    let samples: Vec<(f64, Option<f64>)> = (1..sample_count)
+        .map(|i| {
+            let pitch = -90.0 + i as f64 * PITCH_SCAN_STEP;
+            (pitch, height_error(pitch))
+        })
+        .collect();
+
+    let roots: Vec<f64> = samples
+        .windows(2)
+        .filter_map(|pair| match pair {
+            [(low, Some(low_error)), (high, Some(high_error))]
+                if (*low_error < 0.0) != (*high_error < 0.0) =>
+            {
+                Some(bisect(*low, *high, *low_error, height_error))
+            }
+            _ => None,
+        })
+        .collect();
+
+    // Pitch grows downwards, so the flattest throw is the last root.
+    roots
+        .iter()
+        .rev()
+        .enumerate()
+        .filter_map(|(index, &pitch)| {
+            let arc = if index == 0 {
+                ThrowArc::Low
+            } else {
+                ThrowArc::High
+            };
+            let yaw = aim_yaw(thrower, target_direction, pitch)?;
+            best_representable_throw(
+                thrower,
+                arc,
+                yaw,
+                pitch,
+                destination,
+                max_ticks,
+                world_collisions,
+                version,
+            )
+        })
+        .collect()
+}
+
+/// Yaw that points the pearl's horizontal motion straight at the destination once the
+/// thrower's motion is added. `None` when that motion is too fast to make up for.
+fn aim_yaw(thrower: &Thrower, target_direction: Space3D, pitch: f64) -> Option<f64> {
+    let speed = THROWN_PEARL_SPEED * utils::to_radians(pitch).cos();
+    let carried = Space3D::new(thrower.motion.x, 0.0, thrower.motion.z);
+
+    let along = target_direction.dot(carried);
+    let discriminant = along * along - carried.length_sq() + speed * speed;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let reach = along + discriminant.sqrt();
+    if reach <= 0.0 {
+        return None;
+    }
+
+    let aim = target_direction * reach - carried;
+    Some(utils::to_degrees((-aim.x).atan2(aim.z)))
+}
+
+/// How far above the destination the pearl is when it has covered the horizontal distance
+/// to it, stepping the flight through [`PearlVersion::free_flight_tick`].
+fn height_error(
+    thrower: &Thrower,
+    target_direction: Space3D,
+    destination: Space3D,
+    pitch: f64,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<f64> {
+    let yaw = aim_yaw(thrower, target_direction, pitch)?;
+    let (position, motion) = throw_state(thrower, yaw as f32, pitch as f32);
+    let target_distance = position.distance_2d(&destination);
+
+    let mut previous = position;
+    let (mut current, mut current_motion) = (position, motion);
+    for _ in 0..max_ticks {
+        (current, current_motion) = version.free_flight_tick(current, current_motion);
+        if world_collisions.is_below_world(current.y) {
+            return None;
+        }
+
+        let travelled = current.distance_2d(&position);
+        if travelled >= target_distance {
+            let before = previous.distance_2d(&position);
+            let fraction = (target_distance - before) / (travelled - before);
+            let y = previous.y + (current.y - previous.y) * fraction;
+            return Some(y - destination.y);
+        }
+        previous = current;
+    }
+
+    None
+}
+
+fn bisect(
+    mut low: f64,
+    mut high: f64,
+    low_error: f64,
+    height_error: impl Fn(f64) -> Option<f64>,
+) -> f64 {
+    for _ in 0..BISECTION_STEPS {
+        let middle = (low + high) / 2.0;
+        match height_error(middle) {
+            Some(error) if (error < 0.0) == (low_error < 0.0) => low = middle,
+            _ => high = middle,
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// Tries the F3 angles around `yaw` and `pitch` and keeps the one that gets closest.
+fn best_representable_throw(
+    thrower: &Thrower,
+    arc: ThrowArc,
+    yaw: f64,
+    pitch: f64,
+    destination: Space3D,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<ThrowResult> {
+    let scale = 10f64.powi(F3_ANGLE_DECIMALS);
+    let yaw_steps = (yaw * scale).round() as i64;
+    let pitch_steps = (pitch * scale).round() as i64;
+    let full_turn = (360.0 * scale) as i64;
+    let max_pitch = (90.0 * scale) as i64;
+
+    let mut best: Option<ThrowResult> = None;
+    for yaw_offset in -1..=1 {
+        for pitch_offset in -1..=1 {
+            let mut yaw_steps = (yaw_steps + yaw_offset).rem_euclid(full_turn);
+            if yaw_steps >= full_turn / 2 {
+                yaw_steps -= full_turn;
+            }
+            let pitch_steps = (pitch_steps + pitch_offset).clamp(-max_pitch, max_pitch);
+
+            let Some(candidate) = simulate_throw(
+                thrower,
+                arc,
+                yaw_steps as f64 / scale,
+                pitch_steps as f64 / scale,
+                destination,
+                max_ticks,
+                world_collisions,
+                version,
+            ) else {
+                continue;
+            };
+            if best.is_none_or(|best| candidate.distance < best.distance) {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    best
+}
+
+fn simulate_throw(
+    thrower: &Thrower,
+    arc: ThrowArc,
+    yaw: f64,
+    pitch: f64,
+    destination: Space3D,
+    max_ticks: u32,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Option<ThrowResult> {
+    let (pearl_start_pos, pearl_start_motion) = throw_state(thrower, yaw as f32, pitch as f32);
+    let data = GeneralData {
+        pearl_position: pearl_start_pos,
+        pearl_motion: pearl_start_motion,
+        tnt_charges: Vec::new(),
+    };
+    let result = simulation::run(
+        &data,
+        EntityKind::Pearl,
+        Some(destination),
+        max_ticks,
+        world_collisions,
+        None,
+        version,
+    )?;
+
+    let (tick, pearl_end_pos) = closest_pass(&result.pearl_trace, destination)?;
+
+    Some(ThrowResult {
+        arc,
+        yaw,
+        pitch,
+        pearl_start_pos,
+        pearl_start_motion,
+        tick,
+        pearl_end_pos,
+        distance: pearl_end_pos.distance(&destination),
+        status: result.status,
+    })
+}
+
+/// Closest point to `destination` along the path between ticks, with the tick that ends the
+/// stretch it lies on. Ticks are a block or more apart, so their positions alone would
+/// miss the destination by up to half of that.
+fn closest_pass(trace: &[Space3D], destination: Space3D) -> Option<(u32, Space3D)> {
+    let start = *trace.first()?;
+    let mut best = (0, start, start.distance_sq(&destination));
+
+    for (index, pair) in trace.windows(2).enumerate() {
+        let (from, to) = (pair[0], pair[1]);
+        let step = to - from;
+        let length_sq = step.length_sq();
+        let fraction = if length_sq < FLOAT_PRECISION_EPSILON {
+            0.0
+        } else {
+            ((destination - from).dot(step) / length_sq).clamp(0.0, 1.0)
+        };
+        let point = from + step * fraction;
+        let distance_sq = point.distance_sq(&destination);
+        if distance_sq < best.2 {
+            best = (index as u32 + 1, point, distance_sq);
+        }
+    }
+
+    Some((best.0, best.1))
+}
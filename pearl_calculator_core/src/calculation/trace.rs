@@ -1,9 +1,10 @@
-use crate::calculation::inputs::{Cannon, GeneralData};
-use crate::calculation::results::{CalculationResult, TNTResult};
-use crate::calculation::simulation;
-use crate::physics::aabb::aabb_box::AABBBox;
+use crate::calculation::inputs::{Cannon, GeneralData, TNT};
+use crate::calculation::results::{CalculationResult, GroupTNTResult, TNTResult};
+use crate::calculation::simulation::{self, SimResult};
 use crate::physics::constants::constants::FLOAT_PRECISION_EPSILON;
+use crate::physics::entities::entity_kind::EntityKind;
 use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::collision_world::CollisionWorld;
 use crate::physics::world::direction::Direction;
 use crate::physics::world::space::Space3D;
 use crate::utils::parallel::*;
@@ -19,65 +20,32 @@ pub fn validate_candidates(
     pearl_offset: Space3D,
     destination: Space3D,
     max_distance_sq: f64,
+    world_collisions: &CollisionWorld,
     version: PearlVersion,
 ) -> Vec<TNTResult> {
-    let pearl_start_absolute_pos = pearl_position + pearl_offset;
     let check_3d = vert_vec.length_sq() > FLOAT_PRECISION_EPSILON;
 
     let raw_results: Vec<TNTResult> = candidates
         .into_par_iter()
-        .flat_map(|((r_u32, b_u32, v_u32), mut ticks)| {
-            ticks.sort_unstable();
-            ticks.dedup();
-
-            let max_sim_tick = *ticks.last().unwrap_or(&0);
-            if max_sim_tick == 0 {
-                return Vec::new();
-            }
-
-            let mut valid_ticks_map = vec![false; (max_sim_tick + 1) as usize];
-            for &t in &ticks {
-                valid_ticks_map[t as usize] = true;
-            }
-
+        .flat_map(|((r_u32, b_u32, v_u32), ticks)| {
             let total = r_u32 + b_u32 + v_u32;
 
             let tnt_impact =
                 red_vec * (r_u32 as f64) + blue_vec * (b_u32 as f64) + vert_vec * (v_u32 as f64);
 
-            let data = GeneralData {
-                pearl_position,
-                pearl_motion: pearl_motion + tnt_impact,
-                tnt_charges: vec![],
-            };
+            let mut results = Vec::new();
 
-            let hits = simulation::scan_trajectory(
-                &data,
-                destination,
-                max_sim_tick,
-                &valid_ticks_map,
-                &[],
+            if let Some((best_hit, yaw, pitch)) = find_best_hit(
+                ticks,
+                pearl_position,
+                pearl_motion + tnt_impact,
                 pearl_offset,
-                version,
+                destination,
                 max_distance_sq,
+                world_collisions,
+                version,
                 check_3d,
-            );
-
-            let mut results = Vec::new();
-
-            if let Some(best_hit) = hits.into_iter().min_by(|a, b| {
-                a.distance
-                    .partial_cmp(&b.distance)
-                    .unwrap()
-                    .then_with(|| a.tick.cmp(&b.tick))
-            }) {
-                let flight = best_hit.position - pearl_start_absolute_pos;
-                let h_dist = (flight.x.powi(2) + flight.z.powi(2)).sqrt();
-                let yaw = (-flight.x).atan2(flight.z).to_degrees();
-                let pitch = (-flight.y).atan2(h_dist).to_degrees();
-
-                let out_dir = Direction::from_angle(yaw);
-
+            ) {
                 results.push(TNTResult {
                     distance: best_hit.distance,
                     tick: best_hit.tick,
@@ -87,9 +55,11 @@ pub fn validate_candidates(
                     total,
                     pearl_end_pos: best_hit.position,
                     pearl_end_motion: best_hit.motion,
-                    direction: out_dir,
+                    direction: Direction::from_angle(yaw),
                     yaw,
                     pitch,
+                    conversion: None,
+                    arrival: None,
                 });
             }
             results
@@ -117,22 +87,136 @@ pub fn validate_candidates(
     }
 
     let mut final_results: Vec<TNTResult> = best_map.into_values().collect();
-    final_results.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+    final_results.sort_by(|a, b| a.distance.total_cmp(&b.distance));
     final_results
 }
 
+pub fn validate_group_candidates(
+    candidates: Vec<(Vec<u32>, Vec<u32>)>,
+    group_vecs: &[Space3D],
+    pearl_position: Space3D,
+    pearl_motion: Space3D,
+    pearl_offset: Space3D,
+    destination: Space3D,
+    max_distance_sq: f64,
+    check_3d: bool,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Vec<GroupTNTResult> {
+    let mut results: Vec<GroupTNTResult> = candidates
+        .into_par_iter()
+        .filter_map(|(counts, ticks)| {
+            let tnt_impact = counts
+                .iter()
+                .zip(group_vecs)
+                .fold(Space3D::default(), |acc, (&count, &vec)| {
+                    acc + vec * (count as f64)
+                });
+
+            let (best_hit, yaw, pitch) = find_best_hit(
+                ticks,
+                pearl_position,
+                pearl_motion + tnt_impact,
+                pearl_offset,
+                destination,
+                max_distance_sq,
+                world_collisions,
+                version,
+                check_3d,
+            )?;
+
+            Some(GroupTNTResult {
+                distance: best_hit.distance,
+                tick: best_hit.tick,
+                total: counts.iter().sum(),
+                counts,
+                yaw,
+                pitch,
+                pearl_end_pos: best_hit.position,
+                pearl_end_motion: best_hit.motion,
+                direction: Direction::from_angle(yaw),
+            })
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        a.distance
+            .total_cmp(&b.distance)
+            .then_with(|| a.total.cmp(&b.total))
+            .then_with(|| a.tick.cmp(&b.tick))
+    });
+    results
+}
+
+fn find_best_hit(
+    mut ticks: Vec<u32>,
+    pearl_position: Space3D,
+    launch_motion: Space3D,
+    pearl_offset: Space3D,
+    destination: Space3D,
+    max_distance_sq: f64,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+    check_3d: bool,
+) -> Option<(SimResult, f64, f64)> {
+    ticks.sort_unstable();
+    ticks.dedup();
+
+    let max_sim_tick = *ticks.last().unwrap_or(&0);
+    if max_sim_tick == 0 {
+        return None;
+    }
+
+    let data = GeneralData {
+        pearl_position,
+        pearl_motion: launch_motion,
+        tnt_charges: vec![],
+    };
+
+    let mut valid_ticks_map = vec![false; (max_sim_tick + 1) as usize];
+    for &t in &ticks {
+        valid_ticks_map[t as usize] = true;
+    }
+
+    let hits = simulation::scan_trajectory(
+        &data,
+        destination,
+        max_sim_tick,
+        &valid_ticks_map,
+        world_collisions,
+        pearl_offset,
+        version,
+        max_distance_sq,
+        check_3d,
+    );
+
+    let best_hit = hits.into_iter().min_by(|a, b| {
+        a.distance
+            .total_cmp(&b.distance)
+            .then_with(|| a.tick.cmp(&b.tick))
+    })?;
+
+    let flight = best_hit.position - (pearl_position + pearl_offset);
+    let h_dist = (flight.x.powi(2) + flight.z.powi(2)).sqrt();
+    let yaw = (-flight.x).atan2(flight.z).to_degrees();
+    let pitch = (-flight.y).atan2(h_dist).to_degrees();
+
+    Some((best_hit, yaw, pitch))
+}
+
 pub fn calculate_pearl_trace(
     cannon: &Cannon,
     red_tnt: u32,
     blue_tnt: u32,
     vertical_tnt: u32,
     direction: Direction,
+    kind: EntityKind,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     version: PearlVersion,
 ) -> Option<CalculationResult> {
     let (red_vec, blue_vec, vert_vec) =
-        super::vectors::resolve_vectors_for_direction(cannon, direction);
+        super::vectors::resolve_vectors_for_direction(cannon, direction, kind, world_collisions)?;
 
     let total_tnt_motion = (red_vec * red_tnt as f64)
         + (blue_vec * blue_tnt as f64)
@@ -142,6 +226,8 @@ pub fn calculate_pearl_trace(
     run_trace_internal(
         cannon.pearl.position,
         final_motion,
+        kind,
+        vec![],
         Some(cannon.pearl.offset),
         max_ticks,
         world_collisions,
@@ -152,25 +238,46 @@ pub fn calculate_pearl_trace(
 pub fn calculate_raw_trace(
     pearl_position: Space3D,
     pearl_motion: Space3D,
-    tnt_charges: Vec<(Space3D, u32)>,
+    kind: EntityKind,
+    tnt_charges: Vec<(TNT, u32)>,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     version: PearlVersion,
 ) -> Option<CalculationResult> {
-    let total_explosion_motion = tnt_charges
-        .iter()
+    // Charges without a fuse are folded into the launch motion; the rest are
+    // spawned as primed TNT and explode in tick `fuse`, before the pearl moves in it.
+    let (instant_charges, staged_charges): (Vec<_>, Vec<_>) = tnt_charges
+        .into_iter()
         .filter(|(_, count)| *count > 0)
-        .map(|(tnt_pos, count)| {
-            simulation::calculate_tnt_motion(pearl_position, *tnt_pos) * (*count as f64)
+        .partition(|(tnt, _)| tnt.fuse == 0);
+
+    let total_explosion_motion = instant_charges
+        .iter()
+        .map(|(tnt, count)| {
+            simulation::calculate_explosion_motion(
+                pearl_position,
+                tnt.position,
+                tnt.source,
+                kind,
+                version,
+                world_collisions,
+            ) * (*count as f64)
         })
         .fold(
             Space3D::default(),
             |accumulated_motion, motion_component| accumulated_motion + motion_component,
         );
 
+    let staged_tnt: Vec<TNT> = staged_charges
+        .into_iter()
+        .flat_map(|(tnt, count)| std::iter::repeat_n(tnt, count as usize))
+        .collect();
+
     run_trace_internal(
         pearl_position,
         pearl_motion + total_explosion_motion,
+        kind,
+        staged_tnt,
         None,
         max_ticks,
         world_collisions,
@@ -181,19 +288,22 @@ pub fn calculate_raw_trace(
 fn run_trace_internal(
     position: Space3D,
     motion: Space3D,
+    kind: EntityKind,
+    tnt_charges: Vec<TNT>,
     offset: Option<Space3D>,
     max_ticks: u32,
-    world_collisions: &[AABBBox],
+    world_collisions: &CollisionWorld,
     version: PearlVersion,
 ) -> Option<CalculationResult> {
     let general_data = GeneralData {
         pearl_position: position,
         pearl_motion: motion,
-        tnt_charges: vec![],
+        tnt_charges,
     };
 
     simulation::run(
         &general_data,
+        kind,
         None,
         max_ticks,
         world_collisions,
@@ -1,16 +1,84 @@
-use crate::calculation::inputs::Cannon;
+use crate::calculation::inputs::{Cannon, GroupCannon};
 use crate::calculation::simulation;
+use crate::physics::entities::entity_kind::EntityKind;
+use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::collision_world::CollisionWorld;
 use crate::physics::world::direction::Direction;
 use crate::physics::world::layout_direction::LayoutDirection;
 use crate::physics::world::space::Space3D;
 
+/// `None` when the cannon's dupers cannot steer independently; see [`resolve_tnt_positions`].
 pub fn resolve_vectors_for_direction(
     cannon: &Cannon,
     direction: Direction,
-) -> (Space3D, Space3D, Space3D) {
+    kind: EntityKind,
+    world_collisions: &CollisionWorld,
+) -> Option<(Space3D, Space3D, Space3D)> {
+    let pearl_calc_pos = pearl_calc_position(cannon);
+    let (red_tnt_loc, blue_tnt_loc) = resolve_tnt_positions(cannon, direction)?;
+
+    let red_vec =
+        simulation::calculate_tnt_motion(pearl_calc_pos, red_tnt_loc, kind, world_collisions);
+    let blue_vec =
+        simulation::calculate_tnt_motion(pearl_calc_pos, blue_tnt_loc, kind, world_collisions);
+
+    let vert_vec = if let Some(v_pos) = cannon.vertical_tnt {
+        simulation::calculate_tnt_motion(pearl_calc_pos, v_pos, kind, world_collisions)
+    } else {
+        Space3D::default()
+    };
+
+    Some((red_vec, blue_vec, vert_vec))
+}
+
+/// Where the pearl is taken to be when working out TNT knockback.
+pub fn pearl_calc_position(cannon: &Cannon) -> Space3D {
     let mut pearl_calc_pos = cannon.pearl.offset;
     pearl_calc_pos.y = cannon.pearl.position.y;
+    pearl_calc_pos
+}
+
+/// Red and blue TNT positions for a flight direction, honouring the overrides. `None` when
+/// the layout is needed and its dupers do not pair up (see [`LayoutDirection::pairs_with`]),
+/// since both would then push along the same line.
+pub fn resolve_tnt_positions(cannon: &Cannon, direction: Direction) -> Option<(Space3D, Space3D)> {
+    match (cannon.red_tnt_override, cannon.blue_tnt_override) {
+        (Some(red_tnt_loc), Some(blue_tnt_loc)) => Some((red_tnt_loc, blue_tnt_loc)),
+        (red_override, blue_override) => {
+            let (red_tnt_loc, blue_tnt_loc) = resolve_layout_positions(cannon, direction)?;
+            Some((
+                red_override.unwrap_or(red_tnt_loc),
+                blue_override.unwrap_or(blue_tnt_loc),
+            ))
+        }
+    }
+}
 
+pub fn resolve_group_vectors(
+    cannon: &GroupCannon,
+    world_collisions: &CollisionWorld,
+    version: PearlVersion,
+) -> Vec<Space3D> {
+    let mut pearl_calc_pos = cannon.pearl.offset;
+    pearl_calc_pos.y = cannon.pearl.position.y;
+
+    cannon
+        .groups
+        .iter()
+        .map(|group| {
+            simulation::calculate_explosion_motion(
+                pearl_calc_pos,
+                group.position,
+                group.source,
+                EntityKind::Pearl,
+                version,
+                world_collisions,
+            )
+        })
+        .collect()
+}
+
+fn resolve_layout_positions(cannon: &Cannon, direction: Direction) -> Option<(Space3D, Space3D)> {
     let blue_duper = cannon
         .default_blue_duper
         .unwrap_or(LayoutDirection::NorthEast);
@@ -18,6 +86,15 @@ pub fn resolve_vectors_for_direction(
         .default_red_duper
         .unwrap_or(LayoutDirection::NorthWest);
 
+    if !red_duper.pairs_with(blue_duper) {
+        return None;
+    }
+    if red_duper.is_cardinal() {
+        return Some(resolve_cardinal_positions(
+            cannon, direction, red_duper, blue_duper,
+        ));
+    }
+
     let red_tnt_loc;
     let blue_tnt_loc;
 
@@ -40,16 +117,27 @@ pub fn resolve_vectors_for_direction(
         blue_tnt_loc = tnt_loc_from_layout(cannon, cardinal_bits_to_layout_direction(final_bits));
     }
 
-    let red_vec = simulation::calculate_tnt_motion(pearl_calc_pos, red_tnt_loc);
-    let blue_vec = simulation::calculate_tnt_motion(pearl_calc_pos, blue_tnt_loc);
+    Some((red_tnt_loc, blue_tnt_loc))
+}
 
-    let vert_vec = if let Some(v_pos) = cannon.vertical_tnt {
-        simulation::calculate_tnt_motion(pearl_calc_pos, v_pos)
-    } else {
-        Space3D::default()
-    };
+/// Plus-shaped cannons have one TNT group on each side of the pearl. One duper pushes from
+/// behind the flight direction and the other steers from the side it is placed on, so the
+/// duper that sits on the flight axis is the one moved behind the pearl. The dupers are on
+/// different axes (see [`LayoutDirection::pairs_with`]), so one of them is always off it.
+fn resolve_cardinal_positions(
+    cannon: &Cannon,
+    direction: Direction,
+    red_duper: LayoutDirection,
+    blue_duper: LayoutDirection,
+) -> (Space3D, Space3D) {
+    let flight_axis_bits = direction | direction.invert();
+    let behind = tnt_loc_from_layout(cannon, cardinal_layout_from_direction(direction.invert()));
 
-    (red_vec, blue_vec, vert_vec)
+    if (layout_direction_to_cardinal_bits(blue_duper) & flight_axis_bits) == 0 {
+        (behind, tnt_loc_from_layout(cannon, blue_duper))
+    } else {
+        (tnt_loc_from_layout(cannon, red_duper), behind)
+    }
 }
 
 fn tnt_loc_from_layout(cannon: &Cannon, dir: LayoutDirection) -> Space3D {
@@ -58,7 +146,10 @@ fn tnt_loc_from_layout(cannon: &Cannon, dir: LayoutDirection) -> Space3D {
         LayoutDirection::NorthEast => cannon.north_east_tnt,
         LayoutDirection::SouthWest => cannon.south_west_tnt,
         LayoutDirection::SouthEast => cannon.south_east_tnt,
-        _ => Space3D::default(),
+        LayoutDirection::North => cannon.north_tnt,
+        LayoutDirection::South => cannon.south_tnt,
+        LayoutDirection::West => cannon.west_tnt,
+        LayoutDirection::East => cannon.east_tnt,
     }
 }
 
@@ -68,7 +159,19 @@ fn layout_direction_to_cardinal_bits(dir: LayoutDirection) -> u8 {
         LayoutDirection::NorthEast => (Direction::North as u8) | (Direction::East as u8),
         LayoutDirection::SouthWest => (Direction::South as u8) | (Direction::West as u8),
         LayoutDirection::SouthEast => (Direction::South as u8) | (Direction::East as u8),
-        _ => 0,
+        LayoutDirection::North => Direction::North as u8,
+        LayoutDirection::South => Direction::South as u8,
+        LayoutDirection::West => Direction::West as u8,
+        LayoutDirection::East => Direction::East as u8,
+    }
+}
+
+fn cardinal_layout_from_direction(direction: Direction) -> LayoutDirection {
+    match direction {
+        Direction::North => LayoutDirection::North,
+        Direction::South => LayoutDirection::South,
+        Direction::West => LayoutDirection::West,
+        Direction::East => LayoutDirection::East,
     }
 }
 
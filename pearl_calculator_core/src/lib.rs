@@ -1,4 +1,5 @@
 pub mod calculation;
 pub mod physics;
+pub mod schematic;
 pub mod settings;
 pub mod utils;
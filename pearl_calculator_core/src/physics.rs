@@ -1,4 +1,5 @@
 pub mod aabb;
 pub mod constants;
 pub mod entities;
+pub mod explosion;
 pub mod world;
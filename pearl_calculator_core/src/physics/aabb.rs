@@ -1 +1,2 @@
 pub mod aabb_box;
+pub mod raycast;
@@ -1,3 +1,6 @@
+use crate::physics::constants::constants::FLOAT_PRECISION_EPSILON;
+use crate::physics::world::block_face::BlockFace;
+use crate::physics::world::space::Space3D;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -33,6 +36,60 @@ impl AABBBox {
         }
     }
 
+    pub fn from_corners(a: Space3D, b: Space3D) -> Self {
+        Self::new(
+            a.x.min(b.x),
+            a.y.min(b.y),
+            a.z.min(b.z),
+            a.x.max(b.x),
+            a.y.max(b.y),
+            a.z.max(b.z),
+        )
+    }
+
+    /// Vanilla `expandTowards`: stretches the box along the motion so it covers the sweep.
+    pub fn expand_towards(&self, x: f64, y: f64, z: f64) -> Self {
+        Self::new(
+            self.min_x + x.min(0.0),
+            self.min_y + y.min(0.0),
+            self.min_z + z.min(0.0),
+            self.max_x + x.max(0.0),
+            self.max_y + y.max(0.0),
+            self.max_z + z.max(0.0),
+        )
+    }
+
+    pub fn inflate(&self, amount: f64) -> Self {
+        Self::new(
+            self.min_x - amount,
+            self.min_y - amount,
+            self.min_z - amount,
+            self.max_x + amount,
+            self.max_y + amount,
+            self.max_z + amount,
+        )
+    }
+
+    /// Overlap test that also counts shared faces and edges, for conservative queries.
+    pub fn touches(&self, other: &AABBBox) -> bool {
+        self.min_x <= other.max_x
+            && self.max_x >= other.min_x
+            && self.min_y <= other.max_y
+            && self.max_y >= other.min_y
+            && self.min_z <= other.max_z
+            && self.max_z >= other.min_z
+    }
+
+    /// Strict overlap test; boxes that only share a face do not intersect.
+    pub fn intersects(&self, other: &AABBBox) -> bool {
+        self.min_x < other.max_x
+            && self.max_x > other.min_x
+            && self.min_y < other.max_y
+            && self.max_y > other.min_y
+            && self.min_z < other.max_z
+            && self.max_z > other.min_z
+    }
+
     pub fn offset(&self, x: f64, y: f64, z: f64) -> Self {
         Self::new(
             self.min_x + x,
@@ -109,4 +166,80 @@ impl AABBBox {
         }
         offset_z
     }
+
+    pub fn contains(&self, point: Space3D) -> bool {
+        point.x > self.min_x
+            && point.x < self.max_x
+            && point.y > self.min_y
+            && point.y < self.max_y
+            && point.z > self.min_z
+            && point.z < self.max_z
+    }
+
+    /// Returns the fraction of the segment `from -> to` at which it enters this box and
+    /// the face it enters through, or `None` if the segment misses it. A segment starting
+    /// inside the box hits at 0 on the face opposite to its direction, like vanilla.
+    pub fn clip(&self, from: Space3D, to: Space3D) -> Option<(f64, BlockFace)> {
+        let delta = to - from;
+        if self.contains(from) {
+            return Some((0.0, BlockFace::nearest(delta).opposite()));
+        }
+
+        let mut t_enter = 0.0_f64;
+        let mut t_exit = 1.0_f64;
+        let mut entry_face = None;
+
+        for (start, step, min, max, min_face, max_face) in [
+            (
+                from.x,
+                delta.x,
+                self.min_x,
+                self.max_x,
+                BlockFace::West,
+                BlockFace::East,
+            ),
+            (
+                from.y,
+                delta.y,
+                self.min_y,
+                self.max_y,
+                BlockFace::Down,
+                BlockFace::Up,
+            ),
+            (
+                from.z,
+                delta.z,
+                self.min_z,
+                self.max_z,
+                BlockFace::North,
+                BlockFace::South,
+            ),
+        ] {
+            if step.abs() < FLOAT_PRECISION_EPSILON {
+                if start <= min || start >= max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (min - start) / step;
+            let t2 = (max - start) / step;
+            let (near, far, near_face) = if t1 < t2 {
+                (t1, t2, min_face)
+            } else {
+                (t2, t1, max_face)
+            };
+
+            if near >= t_enter {
+                t_enter = near;
+                entry_face = Some(near_face);
+            }
+            t_exit = t_exit.min(far);
+            if t_enter >= t_exit {
+                return None;
+            }
+        }
+
+        entry_face.map(|face| (t_enter, face))
+    }
 }
@@ -0,0 +1,44 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::constants::constants::RAYCAST_MIN_LENGTH_SQ;
+use crate::physics::world::block_face::BlockFace;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::space::Space3D;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockHit {
+    pub position: Space3D,
+    pub face: BlockFace,
+}
+
+/// Vanilla `ClipContext.Block.COLLIDER` raycast: the nearest collision box the segment
+/// `from -> to` runs into.
+pub fn clip_world(
+    from: Space3D,
+    to: Space3D,
+    world_collisions: &CollisionWorld,
+) -> Option<BlockHit> {
+    if (to - from).length_sq() < RAYCAST_MIN_LENGTH_SQ {
+        return None;
+    }
+
+    let nearby = world_collisions.query(&AABBBox::from_corners(from, to));
+    clip_boxes(from, to, &nearby)
+}
+
+/// [`clip_world`] against boxes the caller already narrowed down, so repeated rays
+/// through the same region share one query.
+pub fn clip_boxes(from: Space3D, to: Space3D, boxes: &[AABBBox]) -> Option<BlockHit> {
+    let delta = to - from;
+    if delta.length_sq() < RAYCAST_MIN_LENGTH_SQ {
+        return None;
+    }
+
+    boxes
+        .iter()
+        .filter_map(|aabb| aabb.clip(from, to))
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(fraction, face)| BlockHit {
+            position: from + delta * fraction,
+            face,
+        })
+}
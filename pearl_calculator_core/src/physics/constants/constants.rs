@@ -10,10 +10,150 @@ pub const TNT_RADIUS: f64 = 0.49;
 
 pub const TNT_HEIGHT: f64 = 0.98;
 
-pub const TNT_EXPLOSION_RADIUS: f64 = 8.0;
+pub const TNT_GRAVITY_ACCELERATION: f64 = 0.04;
+
+pub const TNT_DRAG_MULTIPLIER: f64 = 0.98;
+
+pub const TNT_GROUND_FRICTION: f64 = 0.7;
+
+pub const TNT_GROUND_BOUNCE: f64 = -0.5;
+
+pub const TNT_EXPLOSION_POWER: f64 = 4.0;
+
+pub const END_CRYSTAL_EXPLOSION_POWER: f64 = 6.0;
+
+pub const BED_EXPLOSION_POWER: f64 = 5.0;
+
+pub const RESPAWN_ANCHOR_EXPLOSION_POWER: f64 = 5.0;
+
+pub const CREEPER_EXPLOSION_POWER: f64 = 3.0;
+
+pub const CHARGED_CREEPER_POWER_MULTIPLIER: f64 = 2.0;
+
+/// A TNT minecart adds up to 1.5 power per block per tick of horizontal speed, capped at 5.
+pub const TNT_MINECART_SPEED_POWER: f64 = 1.5;
+
+pub const TNT_MINECART_MAX_SPEED: f64 = 5.0;
+
+pub const WIND_CHARGE_EXPLOSION_POWER: f64 = 1.2;
+
+pub const BREEZE_WIND_CHARGE_EXPLOSION_POWER: f64 = 3.0;
+
+/// Player wind charges push harder than their radius suggests. The experimental builds
+/// before 1.20.5 used 1.1.
+pub const WIND_CHARGE_KNOCKBACK_MULTIPLIER: f64 = 1.22;
+
+pub const LEGACY_WIND_CHARGE_KNOCKBACK_MULTIPLIER: f64 = 1.1;
+
+/// Entities are pushed by explosions within twice the explosion's power.
+pub const EXPLOSION_RADIUS_PER_POWER: f64 = 2.0;
 
 pub const TNT_ENTITY_Y_OFFSET: f64 = 0.06125; // 0.98 * 0.0625
 
 pub const PEARL_EXPLOSION_Y_FACTOR: f64 = 0.85;
 
+pub const RAYCAST_MIN_LENGTH_SQ: f64 = 1.0e-7;
+
+pub const COLLISION_GRID_CELL_SIZE: f64 = 8.0;
+
+pub const COLLISION_GRID_MAX_CELLS_PER_BOX: i64 = 512;
+
 pub const FLOAT_PRECISION_EPSILON: f64 = 1e-10;
+
+/// How far a closed-form pearl position may stray from the stepped one, relative to the size
+/// of the flight. The f64 versions agree to about 1e-13.
+pub const CLOSED_FORM_RELATIVE_SLACK: f64 = 1e-9;
+
+/// `Legacy` rounds its motion through f32 every tick, which the closed form does not.
+pub const LEGACY_CLOSED_FORM_RELATIVE_SLACK: f64 = 1e-4;
+
+pub const PEARL_WATER_DRAG_MULTIPLIER: f64 = 0.8;
+
+pub const FLUID_CONTACT_DEFLATE: f64 = 0.001;
+
+pub const VOID_KILL_DEPTH: f64 = 64.0;
+
+pub const NETHER_CEILING_Y: f64 = 127.0;
+
+pub const WORLD_BORDER_EXTENT: f64 = 30_000_000.0;
+
+pub const CHUNK_SIZE: f64 = 16.0;
+
+/// Ticket level of the chunk a player stands in is `33 - simulation distance`, and entities
+/// only tick in chunks at level 31 or lower.
+pub const PLAYER_TICKET_ENTITY_TICKING_MARGIN: u32 = 2;
+
+pub const NETHER_COORDINATE_SCALE: f64 = 8.0;
+
+pub const PLAYER_WIDTH: f64 = 0.6;
+
+pub const PLAYER_HEIGHT: f64 = 1.8;
+
+pub const PLAYER_EYE_HEIGHT: f64 = 1.62;
+
+pub const PLAYER_MAX_HEALTH: f64 = 20.0;
+
+pub const PLAYER_SAFE_FALL_DISTANCE: f64 = 3.0;
+
+pub const PEARL_TELEPORT_DAMAGE: f64 = 5.0;
+
+/// Vanilla checks suffocation against a flat box at eye height, 80% of the player's width.
+pub const SUFFOCATION_CHECK_WIDTH_FACTOR: f64 = 0.8;
+
+pub const SUFFOCATION_CHECK_HEIGHT: f64 = 1.0e-6;
+
+pub const FALLING_BLOCK_RADIUS: f64 = 0.49;
+
+pub const FALLING_BLOCK_HEIGHT: f64 = 0.98;
+
+pub const FALLING_BLOCK_GRAVITY_ACCELERATION: f64 = 0.04;
+
+pub const FALLING_BLOCK_DRAG_MULTIPLIER: f64 = 0.98;
+
+pub const PLAYER_GRAVITY_ACCELERATION: f64 = 0.08;
+
+/// Airborne living entities keep 91% of their horizontal motion but 98% of their vertical.
+pub const PLAYER_HORIZONTAL_DRAG_MULTIPLIER: f64 = 0.91;
+
+pub const PLAYER_VERTICAL_DRAG_MULTIPLIER: f64 = 0.98;
+
+/// Eye height of entities without their own, as a share of their height.
+pub const DEFAULT_EYE_HEIGHT_FACTOR: f64 = 0.85;
+
+pub const THROWN_PEARL_SPEED: f64 = 1.5;
+
+/// Thrown projectiles spawn this far below the thrower's eyes. It is a float in vanilla.
+pub const THROWN_PEARL_EYE_OFFSET: f64 = 0.1_f32 as f64;
+
+/// F3 shows the player's yaw and pitch to one decimal place.
+pub const F3_ANGLE_DECIMALS: i32 = 1;
+
+/// Dispensed projectiles spawn this far out from the dispenser's center.
+pub const DISPENSER_SPAWN_OFFSET: f64 = 0.7;
+
+pub const DISPENSED_PROJECTILE_SPEED: f64 = 1.1_f32 as f64;
+
+/// Added to the facing's vertical step before aiming, so shots go slightly upwards.
+pub const DISPENSED_PROJECTILE_LIFT: f32 = 0.1;
+
+pub const DISPENSER_INACCURACY: f64 = 6.0;
+
+/// Standard deviation of a projectile's spread per point of inaccuracy, on each axis of
+/// its unit aim.
+pub const PROJECTILE_SPREAD_PER_INACCURACY: f64 = 0.0075;
+
+/// Primed TNT is kicked this fast in a random horizontal direction when it is lit.
+pub const TNT_IGNITION_KICK: f64 = 0.02;
+
+pub const TNT_IGNITION_LIFT: f64 = 0.2_f32 as f64;
+
+pub const TNT_DEFAULT_FUSE: u32 = 80;
+
+/// Chi-squared quantile with two degrees of freedom that bounds 95% of a 2D normal spread.
+pub const CONFIDENCE_95_CHI_SQUARED_2D: f64 = 5.991464547107979;
+
+/// Start position error, in blocks, used to measure how the landing point follows it.
+pub const SENSITIVITY_POSITION_STEP: f64 = 1e-3;
+
+/// Start motion error, in blocks per tick, used to measure how the landing point follows it.
+pub const SENSITIVITY_MOTION_STEP: f64 = 1e-4;
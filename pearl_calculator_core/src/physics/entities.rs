@@ -1,4 +1,5 @@
 pub mod entities;
+pub mod entity_kind;
 pub mod movement;
 pub mod pearl_entities;
 pub mod tnt_entities;
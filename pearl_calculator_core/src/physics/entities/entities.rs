@@ -1,8 +1,10 @@
 use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::fluid::FluidKind;
 use crate::physics::world::space::Space3D;
 
 pub trait EntityTrait {
-    fn tick(&mut self);
+    fn tick(&mut self, world_collisions: &CollisionWorld);
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +16,8 @@ pub struct EntityData {
     pub is_collided_horizontally: bool,
     pub is_collided_vertically: bool,
     pub is_gravity: bool,
+    /// Fluid the entity was in at the start of the current tick.
+    pub fluid: Option<FluidKind>,
 }
 
 impl EntityData {
@@ -26,32 +30,47 @@ impl EntityData {
             is_collided_horizontally: false,
             is_collided_vertically: false,
             is_gravity: false,
+            fluid: None,
         }
     }
 
+    /// Vanilla refreshes this in `baseTick`, before the entity moves, so drag for a tick
+    /// depends on where the tick started.
+    pub fn update_fluid(&mut self, world_collisions: &CollisionWorld) {
+        self.fluid = world_collisions.fluid_at(&self.bounding_box);
+    }
+
+    pub fn set_position(&mut self, position: Space3D) {
+        let delta = position - self.position;
+        self.bounding_box = self.bounding_box.offset(delta.x, delta.y, delta.z);
+        self.position = position;
+    }
+
     pub fn move_entity(
         &mut self,
         mut xa: f64,
         mut ya: f64,
         mut za: f64,
-        world_collisions: &[AABBBox],
+        world_collisions: &CollisionWorld,
     ) {
         let original_xa = xa;
         let original_ya = ya;
         let original_za = za;
 
+        let nearby = world_collisions.query(&self.bounding_box.expand_towards(xa, ya, za));
+
         let mut bb = self.bounding_box;
-        for aabb in world_collisions {
+        for aabb in &nearby {
             ya = aabb.y_offset(&bb, ya);
         }
         bb = bb.offset(0.0, ya, 0.0);
 
-        for aabb in world_collisions {
+        for aabb in &nearby {
             xa = aabb.x_offset(&bb, xa);
         }
         bb = bb.offset(xa, 0.0, 0.0);
 
-        for aabb in world_collisions {
+        for aabb in &nearby {
             za = aabb.z_offset(&bb, za);
         }
 
@@ -0,0 +1,183 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::aabb::raycast::BlockHit;
+use crate::physics::constants::constants::{
+    DEFAULT_EYE_HEIGHT_FACTOR, FALLING_BLOCK_DRAG_MULTIPLIER, FALLING_BLOCK_GRAVITY_ACCELERATION,
+    FALLING_BLOCK_HEIGHT, FALLING_BLOCK_RADIUS, PEARL_DRAG_MULTIPLIER, PEARL_EXPLOSION_Y_FACTOR,
+    PEARL_GRAVITY_ACCELERATION, PEARL_HEIGHT, PEARL_RADIUS, PLAYER_EYE_HEIGHT,
+    PLAYER_GRAVITY_ACCELERATION, PLAYER_HEIGHT, PLAYER_HORIZONTAL_DRAG_MULTIPLIER,
+    PLAYER_VERTICAL_DRAG_MULTIPLIER, PLAYER_WIDTH, TNT_DRAG_MULTIPLIER, TNT_GRAVITY_ACCELERATION,
+    TNT_GROUND_BOUNCE, TNT_GROUND_FRICTION, TNT_HEIGHT, TNT_RADIUS,
+};
+use crate::physics::entities::entities::EntityData;
+use crate::physics::world::block_face::BlockFace;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::space::Space3D;
+use serde::{Deserialize, Serialize};
+
+/// What a cannon launches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum EntityKind {
+    #[default]
+    Pearl,
+    Tnt,
+    FallingBlock,
+    Player,
+}
+
+/// Order of the steps in one tick of a non-projectile entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickOrder {
+    /// Primed TNT and falling blocks.
+    GravityMoveDrag,
+    /// Living entities, including players.
+    MoveGravityDrag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroundBehavior {
+    /// Keeps moving, scaling its motion each tick it ends on the ground.
+    Bounce { friction: f64, bounce: f64 },
+    /// The flight ends once it lands.
+    Stop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityProfile {
+    pub radius: f64,
+    pub height: f64,
+    /// Height above the feet that explosions push towards.
+    pub knockback_height: f64,
+    pub gravity: f64,
+    pub horizontal_drag: f64,
+    pub vertical_drag: f64,
+    /// `None` for projectiles, whose order depends on the version; see `PearlMovement`.
+    pub tick_order: Option<TickOrder>,
+    pub ground: GroundBehavior,
+    /// Whether it keeps the chunk it is in ticking on its own, as players do. Pearls only
+    /// do from 1.21.2 on, which `PearlMovement::LOADS_OWN_CHUNK` covers.
+    pub loads_own_chunk: bool,
+}
+
+impl EntityKind {
+    pub fn profile(&self) -> EntityProfile {
+        match self {
+            EntityKind::Pearl => EntityProfile {
+                radius: PEARL_RADIUS,
+                height: PEARL_HEIGHT,
+                knockback_height: PEARL_EXPLOSION_Y_FACTOR * PEARL_HEIGHT,
+                gravity: PEARL_GRAVITY_ACCELERATION,
+                horizontal_drag: PEARL_DRAG_MULTIPLIER,
+                vertical_drag: PEARL_DRAG_MULTIPLIER,
+                tick_order: None,
+                ground: GroundBehavior::Stop,
+                loads_own_chunk: false,
+            },
+            // Primed TNT is pushed towards its feet rather than its eyes.
+            EntityKind::Tnt => EntityProfile {
+                radius: TNT_RADIUS,
+                height: TNT_HEIGHT,
+                knockback_height: 0.0,
+                gravity: TNT_GRAVITY_ACCELERATION,
+                horizontal_drag: TNT_DRAG_MULTIPLIER,
+                vertical_drag: TNT_DRAG_MULTIPLIER,
+                tick_order: Some(TickOrder::GravityMoveDrag),
+                ground: GroundBehavior::Bounce {
+                    friction: TNT_GROUND_FRICTION,
+                    bounce: TNT_GROUND_BOUNCE,
+                },
+                loads_own_chunk: false,
+            },
+            EntityKind::FallingBlock => EntityProfile {
+                radius: FALLING_BLOCK_RADIUS,
+                height: FALLING_BLOCK_HEIGHT,
+                knockback_height: DEFAULT_EYE_HEIGHT_FACTOR * FALLING_BLOCK_HEIGHT,
+                gravity: FALLING_BLOCK_GRAVITY_ACCELERATION,
+                horizontal_drag: FALLING_BLOCK_DRAG_MULTIPLIER,
+                vertical_drag: FALLING_BLOCK_DRAG_MULTIPLIER,
+                tick_order: Some(TickOrder::GravityMoveDrag),
+                ground: GroundBehavior::Stop,
+                loads_own_chunk: false,
+            },
+            EntityKind::Player => EntityProfile {
+                radius: PLAYER_WIDTH / 2.0,
+                height: PLAYER_HEIGHT,
+                knockback_height: PLAYER_EYE_HEIGHT,
+                gravity: PLAYER_GRAVITY_ACCELERATION,
+                horizontal_drag: PLAYER_HORIZONTAL_DRAG_MULTIPLIER,
+                vertical_drag: PLAYER_VERTICAL_DRAG_MULTIPLIER,
+                tick_order: Some(TickOrder::MoveGravityDrag),
+                ground: GroundBehavior::Stop,
+                loads_own_chunk: true,
+            },
+        }
+    }
+
+    pub fn is_projectile(&self) -> bool {
+        self.profile().tick_order.is_none()
+    }
+}
+
+impl EntityProfile {
+    pub fn bounding_box(&self, position: Space3D) -> AABBBox {
+        AABBBox::new(
+            position.x - self.radius,
+            position.y,
+            position.z - self.radius,
+            position.x + self.radius,
+            position.y + self.height,
+            position.z + self.radius,
+        )
+    }
+
+    /// One tick of a non-projectile entity. Fluids are recorded but do not change its drag.
+    /// Returns where it landed when it stops on the ground.
+    pub fn run_tick_sequence(
+        &self,
+        data: &mut EntityData,
+        world_collisions: &CollisionWorld,
+    ) -> Option<BlockHit> {
+        data.update_fluid(world_collisions);
+
+        match self.tick_order {
+            Some(TickOrder::GravityMoveDrag) | None => {
+                data.motion.y -= self.gravity;
+                data.move_entity(
+                    data.motion.x,
+                    data.motion.y,
+                    data.motion.z,
+                    world_collisions,
+                );
+            }
+            Some(TickOrder::MoveGravityDrag) => {
+                data.move_entity(
+                    data.motion.x,
+                    data.motion.y,
+                    data.motion.z,
+                    world_collisions,
+                );
+                data.motion.y -= self.gravity;
+            }
+        }
+
+        data.motion.x *= self.horizontal_drag;
+        data.motion.y *= self.vertical_drag;
+        data.motion.z *= self.horizontal_drag;
+
+        if !data.on_ground {
+            return None;
+        }
+
+        match self.ground {
+            GroundBehavior::Bounce { friction, bounce } => {
+                data.motion.x *= friction;
+                data.motion.y *= bounce;
+                data.motion.z *= friction;
+                None
+            }
+            GroundBehavior::Stop => Some(BlockHit {
+                position: data.position,
+                face: BlockFace::Up,
+            }),
+        }
+    }
+}
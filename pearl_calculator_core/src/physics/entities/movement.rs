@@ -1,9 +1,16 @@
-use crate::physics::aabb::aabb_box::AABBBox;
-use crate::physics::constants::constants::{PEARL_DRAG_MULTIPLIER, PEARL_GRAVITY_ACCELERATION};
+use crate::physics::aabb::raycast::{self, BlockHit};
+use crate::physics::constants::constants::{
+    CLOSED_FORM_RELATIVE_SLACK, LEGACY_CLOSED_FORM_RELATIVE_SLACK, PEARL_DRAG_MULTIPLIER,
+    PEARL_GRAVITY_ACCELERATION, PEARL_WATER_DRAG_MULTIPLIER,
+};
+use crate::physics::entities::entities::EntityData;
 use crate::physics::entities::pearl_entities::PearlEntity;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::fluid::FluidKind;
+use crate::physics::world::space::Space3D;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PearlVersion {
     /// <= 1.20.4
     /// Float, Move => Drag => Gravity
@@ -32,41 +39,161 @@ impl PearlVersion {
             PearlVersion::Post1212 => drag,
         }
     }
+
+    /// Drag and gravity for one tick, in the order and precision this version applies them.
+    /// `Legacy` rounds the motion through `f32`.
+    pub fn apply_drag_and_gravity(&self, motion: Space3D, drag: f64, is_gravity: bool) -> Space3D {
+        match self {
+            PearlVersion::Legacy => {
+                let drag = drag as f32;
+                let mut mx = motion.x as f32;
+                let mut my = motion.y as f32;
+                let mut mz = motion.z as f32;
+
+                mx *= drag;
+                my *= drag;
+                mz *= drag;
+
+                if is_gravity {
+                    my -= PEARL_GRAVITY_ACCELERATION as f32;
+                }
+
+                Space3D::new(mx as f64, my as f64, mz as f64)
+            }
+            PearlVersion::Post1205 => {
+                let mut motion = motion * drag;
+                if is_gravity {
+                    motion.y -= PEARL_GRAVITY_ACCELERATION;
+                }
+                motion
+            }
+            PearlVersion::Post1212 => {
+                let mut motion = motion;
+                if is_gravity {
+                    motion.y -= PEARL_GRAVITY_ACCELERATION;
+                }
+                motion * drag
+            }
+        }
+    }
+
+    /// Position and motion after one tick of open-air flight. It does what the matching
+    /// [`PearlMovement`] does when nothing is in the way, in the same order, so repeating it
+    /// reproduces the stepped flight bit for bit without raycasts or fluid lookups.
+    pub fn free_flight_tick(&self, position: Space3D, motion: Space3D) -> (Space3D, Space3D) {
+        match self {
+            PearlVersion::Legacy | PearlVersion::Post1205 => (
+                position + motion,
+                self.apply_drag_and_gravity(motion, PEARL_DRAG_MULTIPLIER, true),
+            ),
+            PearlVersion::Post1212 => {
+                let motion = self.apply_drag_and_gravity(motion, PEARL_DRAG_MULTIPLIER, true);
+                (position + motion, motion)
+            }
+        }
+    }
+
+    /// Position after `tick` ticks of open-air flight, summed as geometric series instead of
+    /// stepped. It is not bit-exact, but stays within [`Self::closed_form_slack`] of what
+    /// [`Self::free_flight_tick`] reaches, which is enough to rule a tick out as a hit.
+    pub fn position_at_tick(&self, position: Space3D, motion: Space3D, tick: u32) -> Space3D {
+        let (drag, gravity) = match self {
+            PearlVersion::Legacy => (
+                PEARL_DRAG_MULTIPLIER as f32 as f64,
+                PEARL_GRAVITY_ACCELERATION as f32 as f64,
+            ),
+            PearlVersion::Post1205 | PearlVersion::Post1212 => {
+                (PEARL_DRAG_MULTIPLIER, PEARL_GRAVITY_ACCELERATION)
+            }
+        };
+
+        // Post1212 drags before moving, so every summed term carries one extra factor.
+        let projection = self.get_projection_multiplier(drag);
+        let motion_sum = projection * (1.0 - drag.powi(tick as i32)) / (1.0 - drag);
+        let gravity_sum = gravity * projection / (1.0 - drag) * (tick as f64 - motion_sum);
+
+        let mut final_position = position + motion * motion_sum;
+        final_position.y -= gravity_sum;
+        final_position
+    }
+
+    /// Bound on how far [`Self::position_at_tick`] can be from the stepped position, scaled
+    /// by the coordinates involved and the distance the pearl could cover.
+    pub fn closed_form_slack(&self, position: Space3D, motion: Space3D, tick: u32) -> f64 {
+        let relative = match self {
+            PearlVersion::Legacy => LEGACY_CLOSED_FORM_RELATIVE_SLACK,
+            PearlVersion::Post1205 | PearlVersion::Post1212 => CLOSED_FORM_RELATIVE_SLACK,
+        };
+        let terminal_speed = PEARL_GRAVITY_ACCELERATION / (1.0 - PEARL_DRAG_MULTIPLIER);
+        let reach = position.x.abs().max(position.y.abs()).max(position.z.abs())
+            + tick as f64 * (motion.length() + terminal_speed);
+
+        relative * (1.0 + reach)
+    }
 }
 
+/// Runs one projectile tick. Returns the block hit that ended the flight, after which
+/// the pearl has been discarded and its position is where the owner teleports to.
 pub trait PearlMovement {
-    fn run_tick_sequence(pearl: &mut PearlEntity<Self>, world_collisions: &[AABBBox])
+    const VERSION: PearlVersion;
+
+    /// Whether the pearl keeps the chunk it is in entity-ticking with its own ticket.
+    const LOADS_OWN_CHUNK: bool = false;
+
+    fn run_tick_sequence(
+        pearl: &mut PearlEntity<Self>,
+        world_collisions: &CollisionWorld,
+    ) -> Option<BlockHit>
     where
         Self: Sized;
 }
 
+/// Before 1.21.2 the hit is resolved before moving, so the owner lands on the position the
+/// pearl had at the start of the tick rather than on the impact point.
+fn hit_then_move(
+    pearl_data: &mut EntityData,
+    world_collisions: &CollisionWorld,
+) -> Option<BlockHit> {
+    let next_position = pearl_data.position + pearl_data.motion;
+    if let Some(hit) = raycast::clip_world(pearl_data.position, next_position, world_collisions) {
+        return Some(hit);
+    }
+    pearl_data.set_position(next_position);
+    None
+}
+
+/// Thrown projectiles slow down to 0.8 in water in every version. Lava and buoyancy play
+/// no part: gravity is applied as usual and only the drag factor changes.
+fn drag_multiplier(pearl_data: &EntityData) -> f64 {
+    if pearl_data.fluid == Some(FluidKind::Water) {
+        PEARL_WATER_DRAG_MULTIPLIER
+    } else {
+        PEARL_DRAG_MULTIPLIER
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct MovementLegacy;
 
 impl PearlMovement for MovementLegacy {
-    fn run_tick_sequence(pearl: &mut PearlEntity<Self>, world_collisions: &[AABBBox]) {
-        pearl.data.move_entity(
-            pearl.data.motion.x,
-            pearl.data.motion.y,
-            pearl.data.motion.z,
-            world_collisions,
-        );
-
-        let mut mx = pearl.data.motion.x as f32;
-        let mut my = pearl.data.motion.y as f32;
-        let mut mz = pearl.data.motion.z as f32;
-
-        mx *= PEARL_DRAG_MULTIPLIER as f32;
-        my *= PEARL_DRAG_MULTIPLIER as f32;
-        mz *= PEARL_DRAG_MULTIPLIER as f32;
-
-        if pearl.data.is_gravity {
-            my -= PEARL_GRAVITY_ACCELERATION as f32;
+    const VERSION: PearlVersion = PearlVersion::Legacy;
+
+    fn run_tick_sequence(
+        pearl: &mut PearlEntity<Self>,
+        world_collisions: &CollisionWorld,
+    ) -> Option<BlockHit> {
+        pearl.data.update_fluid(world_collisions);
+        if let Some(hit) = hit_then_move(&mut pearl.data, world_collisions) {
+            return Some(hit);
         }
 
-        pearl.data.motion.x = mx as f64;
-        pearl.data.motion.y = my as f64;
-        pearl.data.motion.z = mz as f64;
+        pearl.data.motion = Self::VERSION.apply_drag_and_gravity(
+            pearl.data.motion,
+            drag_multiplier(&pearl.data),
+            pearl.data.is_gravity,
+        );
+
+        None
     }
 }
 
@@ -74,18 +201,24 @@ impl PearlMovement for MovementLegacy {
 pub struct MovementPost1205;
 
 impl PearlMovement for MovementPost1205 {
-    fn run_tick_sequence(pearl: &mut PearlEntity<Self>, world_collisions: &[AABBBox]) {
-        pearl.data.move_entity(
-            pearl.data.motion.x,
-            pearl.data.motion.y,
-            pearl.data.motion.z,
-            world_collisions,
+    const VERSION: PearlVersion = PearlVersion::Post1205;
+
+    fn run_tick_sequence(
+        pearl: &mut PearlEntity<Self>,
+        world_collisions: &CollisionWorld,
+    ) -> Option<BlockHit> {
+        pearl.data.update_fluid(world_collisions);
+        if let Some(hit) = hit_then_move(&mut pearl.data, world_collisions) {
+            return Some(hit);
+        }
+
+        pearl.data.motion = Self::VERSION.apply_drag_and_gravity(
+            pearl.data.motion,
+            drag_multiplier(&pearl.data),
+            pearl.data.is_gravity,
         );
 
-        pearl.data.motion *= PEARL_DRAG_MULTIPLIER;
-        if pearl.data.is_gravity {
-            pearl.data.motion.y -= PEARL_GRAVITY_ACCELERATION;
-        }
+        None
     }
 }
 
@@ -93,17 +226,26 @@ impl PearlMovement for MovementPost1205 {
 pub struct MovementPost1212;
 
 impl PearlMovement for MovementPost1212 {
-    fn run_tick_sequence(pearl: &mut PearlEntity<Self>, world_collisions: &[AABBBox]) {
-        if pearl.data.is_gravity {
-            pearl.data.motion.y -= PEARL_GRAVITY_ACCELERATION;
-        }
-        pearl.data.motion *= PEARL_DRAG_MULTIPLIER;
-
-        pearl.data.move_entity(
-            pearl.data.motion.x,
-            pearl.data.motion.y,
-            pearl.data.motion.z,
-            world_collisions,
+    const VERSION: PearlVersion = PearlVersion::Post1212;
+    const LOADS_OWN_CHUNK: bool = true;
+
+    fn run_tick_sequence(
+        pearl: &mut PearlEntity<Self>,
+        world_collisions: &CollisionWorld,
+    ) -> Option<BlockHit> {
+        pearl.data.update_fluid(world_collisions);
+        pearl.data.motion = Self::VERSION.apply_drag_and_gravity(
+            pearl.data.motion,
+            drag_multiplier(&pearl.data),
+            pearl.data.is_gravity,
         );
+
+        let next_position = pearl.data.position + pearl.data.motion;
+        let hit = raycast::clip_world(pearl.data.position, next_position, world_collisions);
+        pearl
+            .data
+            .set_position(hit.map_or(next_position, |hit| hit.position));
+
+        hit
     }
 }
@@ -1,8 +1,7 @@
 use std::marker::PhantomData;
 
-use crate::physics::aabb::aabb_box::AABBBox;
-use crate::physics::constants::constants::{PEARL_HEIGHT, PEARL_RADIUS};
 use crate::physics::entities::entities::EntityData;
+use crate::physics::entities::entity_kind::EntityKind;
 use crate::physics::entities::movement::PearlMovement;
 use crate::physics::world::space::Space3D;
 
@@ -14,14 +13,13 @@ pub struct PearlEntity<M: PearlMovement> {
 
 impl<M: PearlMovement> PearlEntity<M> {
     pub fn new(position: Space3D, motion: Space3D) -> Self {
-        let bounding_box = AABBBox::new(
-            position.x - PEARL_RADIUS,
-            position.y,
-            position.z - PEARL_RADIUS,
-            position.x + PEARL_RADIUS,
-            position.y + PEARL_HEIGHT,
-            position.z + PEARL_RADIUS,
-        );
+        Self::with_kind(position, motion, EntityKind::Pearl)
+    }
+
+    /// Launches something other than a pearl. Only projectiles tick with `M`; other kinds
+    /// follow their profile and `M` only picks the version.
+    pub fn with_kind(position: Space3D, motion: Space3D, kind: EntityKind) -> Self {
+        let bounding_box = kind.profile().bounding_box(position);
         let mut data = EntityData::new(position, motion, bounding_box);
         data.is_gravity = true;
 
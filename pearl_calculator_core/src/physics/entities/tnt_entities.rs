@@ -1,16 +1,22 @@
 use crate::physics::aabb::aabb_box::AABBBox;
-use crate::physics::constants::constants::{TNT_HEIGHT, TNT_RADIUS};
+use crate::physics::constants::constants::{
+    TNT_DRAG_MULTIPLIER, TNT_GRAVITY_ACCELERATION, TNT_GROUND_BOUNCE, TNT_GROUND_FRICTION,
+    TNT_HEIGHT, TNT_RADIUS,
+};
 use crate::physics::entities::entities::{EntityData, EntityTrait};
+use crate::physics::explosion::source::ExplosionSource;
+use crate::physics::world::collision_world::CollisionWorld;
 use crate::physics::world::space::Space3D;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TNTEntity {
     pub data: EntityData,
     pub fuse: u32,
+    pub source: ExplosionSource,
 }
 
 impl TNTEntity {
-    pub fn new(position: Space3D, fuse: u32) -> Self {
+    pub fn new(position: Space3D, fuse: u32, source: ExplosionSource) -> Self {
         let bounding_box = AABBBox::new(
             position.x - TNT_RADIUS,
             position.y,
@@ -19,14 +25,33 @@ impl TNTEntity {
             position.y + TNT_HEIGHT,
             position.z + TNT_RADIUS,
         );
-        let data = EntityData::new(position, Space3D::default(), bounding_box);
+        let mut data = EntityData::new(position, Space3D::default(), bounding_box);
+        data.is_gravity = source.is_mobile();
 
-        Self { data, fuse }
+        Self { data, fuse, source }
     }
 }
 
 impl EntityTrait for TNTEntity {
-    fn tick(&mut self) {
+    fn tick(&mut self, world_collisions: &CollisionWorld) {
+        if self.data.is_gravity {
+            self.data.motion.y -= TNT_GRAVITY_ACCELERATION;
+        }
+
+        self.data.move_entity(
+            self.data.motion.x,
+            self.data.motion.y,
+            self.data.motion.z,
+            world_collisions,
+        );
+
+        self.data.motion *= TNT_DRAG_MULTIPLIER;
+        if self.data.on_ground {
+            self.data.motion.x *= TNT_GROUND_FRICTION;
+            self.data.motion.y *= TNT_GROUND_BOUNCE;
+            self.data.motion.z *= TNT_GROUND_FRICTION;
+        }
+
         if self.fuse > 0 {
             self.fuse -= 1;
         }
@@ -0,0 +1,2 @@
+pub mod exposure;
+pub mod source;
@@ -0,0 +1,66 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::aabb::raycast;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::space::Space3D;
+
+/// Vanilla `Explosion.getSeenPercent`: the share of sample points on the entity's
+/// bounding box that have an unobstructed line to the explosion center.
+pub fn calculate_seen_percent(
+    center: Space3D,
+    bounding_box: &AABBBox,
+    world_collisions: &CollisionWorld,
+) -> f64 {
+    let step_x = 1.0 / ((bounding_box.max_x - bounding_box.min_x) * 2.0 + 1.0);
+    let step_y = 1.0 / ((bounding_box.max_y - bounding_box.min_y) * 2.0 + 1.0);
+    let step_z = 1.0 / ((bounding_box.max_z - bounding_box.min_z) * 2.0 + 1.0);
+
+    if step_x < 0.0 || step_y < 0.0 || step_z < 0.0 {
+        return 0.0;
+    }
+
+    let offset_x = (1.0 - (1.0 / step_x).floor() * step_x) / 2.0;
+    let offset_z = (1.0 - (1.0 / step_z).floor() * step_z) / 2.0;
+
+    // Samples are shifted by less than one block, so this region covers every ray.
+    let sample_region = bounding_box.inflate(1.0);
+    let nearby = world_collisions.query(&AABBBox::new(
+        sample_region.min_x.min(center.x),
+        sample_region.min_y.min(center.y),
+        sample_region.min_z.min(center.z),
+        sample_region.max_x.max(center.x),
+        sample_region.max_y.max(center.y),
+        sample_region.max_z.max(center.z),
+    ));
+
+    let mut visible = 0u32;
+    let mut total = 0u32;
+
+    let mut fx = 0.0;
+    while fx <= 1.0 {
+        let mut fy = 0.0;
+        while fy <= 1.0 {
+            let mut fz = 0.0;
+            while fz <= 1.0 {
+                let sample = Space3D::new(
+                    lerp(fx, bounding_box.min_x, bounding_box.max_x) + offset_x,
+                    lerp(fy, bounding_box.min_y, bounding_box.max_y),
+                    lerp(fz, bounding_box.min_z, bounding_box.max_z) + offset_z,
+                );
+
+                if raycast::clip_boxes(sample, center, &nearby).is_none() {
+                    visible += 1;
+                }
+                total += 1;
+                fz += step_z;
+            }
+            fy += step_y;
+        }
+        fx += step_x;
+    }
+
+    (visible as f32 / total as f32) as f64
+}
+
+fn lerp(delta: f64, start: f64, end: f64) -> f64 {
+    start + delta * (end - start)
+}
@@ -0,0 +1,100 @@
+use crate::physics::constants::constants::{
+    BED_EXPLOSION_POWER, BREEZE_WIND_CHARGE_EXPLOSION_POWER, CHARGED_CREEPER_POWER_MULTIPLIER,
+    CREEPER_EXPLOSION_POWER, END_CRYSTAL_EXPLOSION_POWER, EXPLOSION_RADIUS_PER_POWER,
+    LEGACY_WIND_CHARGE_KNOCKBACK_MULTIPLIER, RESPAWN_ANCHOR_EXPLOSION_POWER, TNT_ENTITY_Y_OFFSET,
+    TNT_EXPLOSION_POWER, TNT_MINECART_MAX_SPEED, TNT_MINECART_SPEED_POWER,
+    WIND_CHARGE_EXPLOSION_POWER, WIND_CHARGE_KNOCKBACK_MULTIPLIER,
+};
+use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::space::Space3D;
+use serde::{Deserialize, Serialize};
+
+/// What blows up at a charge's position. Entity sources are placed at the entity's position;
+/// beds and respawn anchors at any point inside their block, since they explode at its center.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ExplosionSource {
+    #[default]
+    Tnt,
+    EndCrystal,
+    Bed,
+    RespawnAnchor,
+    /// `speed` is the cart's horizontal speed in blocks per tick when it explodes.
+    TntMinecart {
+        speed: f64,
+    },
+    Creeper {
+        charged: bool,
+    },
+    /// A player's wind charge bursting at the given position. It breaks no blocks, so
+    /// boosters built from it survive every shot.
+    WindCharge,
+    BreezeWindCharge,
+}
+
+impl ExplosionSource {
+    /// Minecart power has a random part in vanilla; this is the middle of its range.
+    pub fn power(&self) -> f64 {
+        match self {
+            ExplosionSource::Tnt => TNT_EXPLOSION_POWER,
+            ExplosionSource::EndCrystal => END_CRYSTAL_EXPLOSION_POWER,
+            ExplosionSource::Bed => BED_EXPLOSION_POWER,
+            ExplosionSource::RespawnAnchor => RESPAWN_ANCHOR_EXPLOSION_POWER,
+            ExplosionSource::TntMinecart { speed } => {
+                TNT_EXPLOSION_POWER
+                    + TNT_MINECART_SPEED_POWER * speed.abs().min(TNT_MINECART_MAX_SPEED) / 2.0
+            }
+            ExplosionSource::Creeper { charged: false } => CREEPER_EXPLOSION_POWER,
+            ExplosionSource::Creeper { charged: true } => {
+                CREEPER_EXPLOSION_POWER * CHARGED_CREEPER_POWER_MULTIPLIER
+            }
+            ExplosionSource::WindCharge => WIND_CHARGE_EXPLOSION_POWER,
+            ExplosionSource::BreezeWindCharge => BREEZE_WIND_CHARGE_EXPLOSION_POWER,
+        }
+    }
+
+    /// Scales the push after distance and exposure falloff.
+    pub fn knockback_multiplier(&self, version: PearlVersion) -> f64 {
+        match (self, version) {
+            (ExplosionSource::WindCharge, PearlVersion::Legacy) => {
+                LEGACY_WIND_CHARGE_KNOCKBACK_MULTIPLIER
+            }
+            (ExplosionSource::WindCharge, _) => WIND_CHARGE_KNOCKBACK_MULTIPLIER,
+            _ => 1.0,
+        }
+    }
+
+    pub fn knockback_radius(&self) -> f64 {
+        self.power() * EXPLOSION_RADIUS_PER_POWER
+    }
+
+    /// Primed TNT explodes a sixteenth of its height above its feet, block sources at the
+    /// center of their block, and every other entity at its feet.
+    pub fn center(&self, position: Space3D) -> Space3D {
+        match self {
+            ExplosionSource::Tnt => {
+                Space3D::new(position.x, position.y + TNT_ENTITY_Y_OFFSET, position.z)
+            }
+            ExplosionSource::Bed | ExplosionSource::RespawnAnchor => Space3D::new(
+                position.x.floor() + 0.5,
+                position.y.floor() + 0.5,
+                position.z.floor() + 0.5,
+            ),
+            ExplosionSource::EndCrystal
+            | ExplosionSource::TntMinecart { .. }
+            | ExplosionSource::Creeper { .. }
+            | ExplosionSource::WindCharge
+            | ExplosionSource::BreezeWindCharge => position,
+        }
+    }
+
+    /// Sources that fall and get pushed around before exploding. Minecarts and creepers use
+    /// primed TNT physics as an approximation; crystals, wind charges and blocks stay put.
+    pub fn is_mobile(&self) -> bool {
+        matches!(
+            self,
+            ExplosionSource::Tnt
+                | ExplosionSource::TntMinecart { .. }
+                | ExplosionSource::Creeper { .. }
+        )
+    }
+}
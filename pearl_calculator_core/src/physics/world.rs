@@ -1,3 +1,8 @@
+pub mod block_face;
+pub mod chunk;
+pub mod collision_world;
+pub mod dimension;
 pub mod direction;
+pub mod fluid;
 pub mod layout_direction;
 pub mod space;
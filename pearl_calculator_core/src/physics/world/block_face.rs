@@ -0,0 +1,57 @@
+use crate::physics::world::space::Space3D;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlockFace {
+    Down,
+    Up,
+    North,
+    South,
+    West,
+    East,
+}
+
+impl BlockFace {
+    pub fn opposite(&self) -> BlockFace {
+        match self {
+            BlockFace::Down => BlockFace::Up,
+            BlockFace::Up => BlockFace::Down,
+            BlockFace::North => BlockFace::South,
+            BlockFace::South => BlockFace::North,
+            BlockFace::West => BlockFace::East,
+            BlockFace::East => BlockFace::West,
+        }
+    }
+
+    pub fn normal(&self) -> Space3D {
+        match self {
+            BlockFace::Down => Space3D::new(0.0, -1.0, 0.0),
+            BlockFace::Up => Space3D::new(0.0, 1.0, 0.0),
+            BlockFace::North => Space3D::new(0.0, 0.0, -1.0),
+            BlockFace::South => Space3D::new(0.0, 0.0, 1.0),
+            BlockFace::West => Space3D::new(-1.0, 0.0, 0.0),
+            BlockFace::East => Space3D::new(1.0, 0.0, 0.0),
+        }
+    }
+
+    /// Vanilla `Direction.getNearest`: the face whose normal best matches `vector`.
+    pub fn nearest(vector: Space3D) -> BlockFace {
+        let mut best = BlockFace::North;
+        let mut best_dot = f64::MIN;
+        for face in [
+            BlockFace::Down,
+            BlockFace::Up,
+            BlockFace::North,
+            BlockFace::South,
+            BlockFace::West,
+            BlockFace::East,
+        ] {
+            let dot = face.normal().dot(vector);
+            if dot > best_dot {
+                best_dot = dot;
+                best = face;
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,117 @@
+use crate::physics::constants::constants::{CHUNK_SIZE, PLAYER_TICKET_ENTITY_TICKING_MARGIN};
+use crate::physics::world::space::Space3D;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ChunkPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl ChunkPos {
+    pub fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    pub fn from_block(x: f64, z: f64) -> Self {
+        Self::new(
+            (x / CHUNK_SIZE).floor() as i32,
+            (z / CHUNK_SIZE).floor() as i32,
+        )
+    }
+
+    pub fn from_position(position: Space3D) -> Self {
+        Self::from_block(position.x, position.z)
+    }
+
+    pub fn region(&self) -> (i32, i32) {
+        (self.x >> 5, self.z >> 5)
+    }
+
+    /// Chebyshev distance in chunks, the metric chunk tickets spread with.
+    pub fn distance(&self, other: &ChunkPos) -> u32 {
+        (self.x - other.x)
+            .unsigned_abs()
+            .max((self.z - other.z).unsigned_abs())
+    }
+}
+
+/// Which chunks tick entities. Entities elsewhere are frozen in place until their chunk
+/// becomes entity-ticking again.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChunkLoading {
+    /// Exactly these chunks tick entities.
+    Chunks(HashSet<ChunkPos>),
+    /// Every chunk within `radius` of a loader ticks entities.
+    Loaders { loaders: Vec<ChunkPos>, radius: u32 },
+}
+
+impl ChunkLoading {
+    /// Players loading the world with the server's simulation distance.
+    pub fn from_simulation_distance(players: Vec<ChunkPos>, simulation_distance: u32) -> Self {
+        ChunkLoading::Loaders {
+            loaders: players,
+            radius: entity_ticking_radius(simulation_distance),
+        }
+    }
+
+    pub fn is_entity_ticking(&self, chunk: ChunkPos) -> bool {
+        match self {
+            ChunkLoading::Chunks(chunks) => chunks.contains(&chunk),
+            ChunkLoading::Loaders { loaders, radius } => loaders
+                .iter()
+                .any(|loader| loader.distance(&chunk) <= *radius),
+        }
+    }
+}
+
+/// Chunks around a player that tick entities: two short of the simulation distance.
+pub fn entity_ticking_radius(simulation_distance: u32) -> u32 {
+    simulation_distance.saturating_sub(PLAYER_TICKET_ENTITY_TICKING_MARGIN)
+}
+
+/// Grid walk over the chunk columns the segment crosses, reporting the segment
+/// fractions spent inside each.
+pub fn for_each_chunk_on_segment(
+    from: Space3D,
+    to: Space3D,
+    mut visit: impl FnMut(ChunkPos, f64, f64),
+) {
+    let (dx, dz) = (to.x - from.x, to.z - from.z);
+    let mut chunk = ChunkPos::from_block(from.x, from.z);
+    let end = ChunkPos::from_block(to.x, to.z);
+
+    let axis = |start: f64, delta: f64, cell: i32| -> (i32, f64, f64) {
+        if delta > 0.0 {
+            let boundary = (cell + 1) as f64 * CHUNK_SIZE;
+            (1, (boundary - start) / delta, CHUNK_SIZE / delta)
+        } else if delta < 0.0 {
+            let boundary = cell as f64 * CHUNK_SIZE;
+            (-1, (boundary - start) / delta, -CHUNK_SIZE / delta)
+        } else {
+            (0, f64::INFINITY, f64::INFINITY)
+        }
+    };
+    let (step_x, mut t_max_x, t_delta_x) = axis(from.x, dx, chunk.x);
+    let (step_z, mut t_max_z, t_delta_z) = axis(from.z, dz, chunk.z);
+
+    let max_steps = (end.x - chunk.x).unsigned_abs() + (end.z - chunk.z).unsigned_abs();
+    let mut t = 0.0;
+    for _ in 0..=max_steps {
+        let t_next = t_max_x.min(t_max_z).min(1.0);
+        visit(chunk, t, t_next);
+        if chunk == end || t_next >= 1.0 {
+            break;
+        }
+
+        if t_max_x < t_max_z {
+            chunk.x += step_x;
+            t = t_max_x;
+            t_max_x += t_delta_x;
+        } else {
+            chunk.z += step_z;
+            t = t_max_z;
+            t_max_z += t_delta_z;
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::constants::constants::{
+    COLLISION_GRID_CELL_SIZE, COLLISION_GRID_MAX_CELLS_PER_BOX, FLUID_CONTACT_DEFLATE,
+};
+use crate::physics::world::chunk::ChunkLoading;
+use crate::physics::world::dimension::DimensionSettings;
+use crate::physics::world::fluid::{FluidKind, FluidRegion};
+use std::collections::HashMap;
+
+type CellKey = (i64, i64, i64);
+
+/// World collision boxes bucketed into a uniform grid, so movement and raycasts only
+/// look at boxes near the swept region instead of the whole world.
+///
+/// Queries return boxes in their original order, which keeps every consumer identical
+/// to iterating the full list: the skipped boxes cannot touch the queried region.
+///
+/// Fluids are kept apart from the solid boxes since nothing collides with them; they are
+/// only looked up to tell whether an entity is inside one.
+#[derive(Debug, Clone, Default)]
+pub struct CollisionWorld {
+    boxes: Vec<AABBBox>,
+    /// How many of the leading `boxes` are the world's own; the rest come from the dimension.
+    terrain: usize,
+    cells: HashMap<CellKey, Vec<usize>>,
+    oversized: Vec<usize>,
+    water: Option<Box<CollisionWorld>>,
+    lava: Option<Box<CollisionWorld>>,
+    dimension: Option<DimensionSettings>,
+    chunk_loading: Option<ChunkLoading>,
+}
+
+impl CollisionWorld {
+    pub fn new(boxes: Vec<AABBBox>) -> Self {
+        let mut cells: HashMap<CellKey, Vec<usize>> = HashMap::new();
+        let mut oversized = Vec::new();
+
+        for (index, aabb) in boxes.iter().enumerate() {
+            let (min, max) = cell_range(aabb);
+            if cell_count(min, max) > COLLISION_GRID_MAX_CELLS_PER_BOX {
+                oversized.push(index);
+                continue;
+            }
+
+            for x in min.0..=max.0 {
+                for y in min.1..=max.1 {
+                    for z in min.2..=max.2 {
+                        cells.entry((x, y, z)).or_default().push(index);
+                    }
+                }
+            }
+        }
+
+        Self {
+            terrain: boxes.len(),
+            boxes,
+            cells,
+            oversized,
+            water: None,
+            lava: None,
+            dimension: None,
+            chunk_loading: None,
+        }
+    }
+
+    pub fn with_fluids(mut self, fluids: Vec<FluidRegion>) -> Self {
+        let of_kind = |kind: FluidKind| {
+            let bounds: Vec<AABBBox> = fluids
+                .iter()
+                .filter(|fluid| fluid.kind == kind)
+                .map(|fluid| fluid.bounds)
+                .collect();
+            (!bounds.is_empty()).then(|| Box::new(CollisionWorld::new(bounds)))
+        };
+        self.water = of_kind(FluidKind::Water);
+        self.lava = of_kind(FluidKind::Lava);
+        self
+    }
+
+    /// Applies a dimension's height limits, adding its bedrock ceiling to the boxes. It
+    /// replaces any dimension applied before. Without one, entities fly forever in every
+    /// direction.
+    pub fn with_dimension(self, dimension: DimensionSettings) -> Self {
+        let terrain = self.terrain;
+        let mut boxes = self.boxes;
+        boxes.truncate(terrain);
+        boxes.extend(dimension.ceiling_box());
+        Self {
+            terrain,
+            water: self.water,
+            lava: self.lava,
+            dimension: Some(dimension),
+            chunk_loading: self.chunk_loading,
+            ..Self::new(boxes)
+        }
+    }
+
+    /// Limits entity ticking to the given chunks. Without it, every chunk ticks.
+    pub fn with_chunk_loading(mut self, chunk_loading: ChunkLoading) -> Self {
+        self.chunk_loading = Some(chunk_loading);
+        self
+    }
+
+    pub fn chunk_loading(&self) -> Option<&ChunkLoading> {
+        self.chunk_loading.as_ref()
+    }
+
+    pub fn dimension(&self) -> Option<&DimensionSettings> {
+        self.dimension.as_ref()
+    }
+
+    pub fn void_kill_y(&self) -> Option<f64> {
+        self.dimension.map(|dimension| dimension.void_kill_y())
+    }
+
+    pub fn is_below_world(&self, y: f64) -> bool {
+        self.void_kill_y().is_some_and(|kill_y| y < kill_y)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+            && self.water.is_none()
+            && self.lava.is_none()
+            && self.chunk_loading.is_none()
+    }
+
+    pub fn boxes(&self) -> &[AABBBox] {
+        &self.boxes
+    }
+
+    /// Whether any box is the world's own rather than the dimension's ceiling.
+    pub fn has_terrain(&self) -> bool {
+        self.terrain > 0
+    }
+
+    /// All boxes touching `region`, in insertion order.
+    pub fn query(&self, region: &AABBBox) -> Vec<AABBBox> {
+        if self.boxes.is_empty() {
+            return Vec::new();
+        }
+
+        let (min, max) = cell_range(region);
+        if cell_count(min, max) >= self.boxes.len() as i64 {
+            return self
+                .boxes
+                .iter()
+                .filter(|aabb| aabb.touches(region))
+                .copied()
+                .collect();
+        }
+
+        let mut indices = self.oversized.clone();
+        for x in min.0..=max.0 {
+            for y in min.1..=max.1 {
+                for z in min.2..=max.2 {
+                    if let Some(cell) = self.cells.get(&(x, y, z)) {
+                        indices.extend_from_slice(cell);
+                    }
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+            .into_iter()
+            .map(|index| self.boxes[index])
+            .filter(|aabb| aabb.touches(region))
+            .collect()
+    }
+
+    /// Fluid an entity with `bounding_box` is in, checked like vanilla's fluid contact
+    /// update: the box shrunk by 0.001 must reach into the fluid volume. Water wins when
+    /// both are present.
+    pub fn fluid_at(&self, bounding_box: &AABBBox) -> Option<FluidKind> {
+        let contact = bounding_box.inflate(-FLUID_CONTACT_DEFLATE);
+        let contains = |fluid: &Option<Box<CollisionWorld>>| {
+            fluid
+                .as_ref()
+                .is_some_and(|fluid| !fluid.query(&contact).is_empty())
+        };
+
+        if contains(&self.water) {
+            Some(FluidKind::Water)
+        } else if contains(&self.lava) {
+            Some(FluidKind::Lava)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Vec<AABBBox>> for CollisionWorld {
+    fn from(boxes: Vec<AABBBox>) -> Self {
+        Self::new(boxes)
+    }
+}
+
+fn cell_range(aabb: &AABBBox) -> (CellKey, CellKey) {
+    let cell = |value: f64| (value / COLLISION_GRID_CELL_SIZE).floor() as i64;
+    (
+        (cell(aabb.min_x), cell(aabb.min_y), cell(aabb.min_z)),
+        (cell(aabb.max_x), cell(aabb.max_y), cell(aabb.max_z)),
+    )
+}
+
+fn cell_count(min: CellKey, max: CellKey) -> i64 {
+    (max.0 - min.0 + 1)
+        .saturating_mul(max.1 - min.1 + 1)
+        .saturating_mul(max.2 - min.2 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::world::dimension::Dimension;
+    use crate::utils::java_random::JavaRandom;
+
+    fn random_box(random: &mut JavaRandom, spread: f64, max_size: f64) -> AABBBox {
+        let mut coordinate = || (random.next_double() - 0.5) * spread;
+        let (x, y, z) = (coordinate(), coordinate(), coordinate());
+        let mut size = || random.next_double() * max_size;
+        AABBBox::new(x, y, z, x + size(), y + size(), z + size())
+    }
+
+    #[test]
+    fn grid_query_matches_linear_scan() {
+        let mut random = JavaRandom::new(9);
+        let cell = COLLISION_GRID_CELL_SIZE;
+        let mut boxes: Vec<AABBBox> = (0..400)
+            .map(|_| random_box(&mut random, 20.0 * cell, 3.0 * cell))
+            .collect();
+        // Whole blocks on cell edges, and boxes too big for the grid.
+        boxes.push(AABBBox::new(-cell, -cell, -cell, 0.0, 0.0, 0.0));
+        boxes.push(AABBBox::new(-1.0, 0.0, -1.0, 0.0, 1.0, 0.0));
+        boxes.push(AABBBox::new(-1000.0, -1.0, -1000.0, 1000.0, 0.0, 1000.0));
+        boxes.push(AABBBox::new(-4.0, -5000.0, 2.0, -3.0, 5000.0, 3.0));
+        let world = CollisionWorld::new(boxes.clone());
+        assert_eq!(world.oversized.len(), 2);
+
+        let regions = (0..300)
+            .map(|_| random_box(&mut random, 24.0 * cell, 2.0 * cell))
+            .chain([
+                AABBBox::new(-cell, -cell, -cell, -cell, -cell, -cell),
+                AABBBox::new(-0.5, 0.0, -0.5, -0.5, 0.0, -0.5),
+                AABBBox::new(-400.0, -400.0, -400.0, 400.0, 400.0, 400.0),
+            ]);
+        for region in regions {
+            let linear: Vec<AABBBox> = boxes
+                .iter()
+                .filter(|aabb| aabb.touches(&region))
+                .copied()
+                .collect();
+            assert_eq!(world.query(&region), linear, "{region:?}");
+        }
+    }
+
+    #[test]
+    fn applying_a_dimension_again_replaces_it() {
+        let floor = AABBBox::new(-8.0, 63.0, -8.0, 8.0, 64.0, 8.0);
+        let world = CollisionWorld::new(vec![floor])
+            .with_dimension(Dimension::Nether.settings())
+            .with_dimension(Dimension::Nether.settings());
+        assert_eq!(world.boxes().len(), 2);
+        assert_eq!(world.boxes()[0], floor);
+
+        let world = world.with_dimension(Dimension::Overworld.settings());
+        assert_eq!(world.boxes(), &[floor]);
+        assert_eq!(world.dimension(), Some(&Dimension::Overworld.settings()));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::constants::constants::{
+    NETHER_CEILING_Y, NETHER_COORDINATE_SCALE, VOID_KILL_DEPTH, WORLD_BORDER_EXTENT,
+};
+use crate::physics::world::space::Space3D;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Dimension {
+    #[default]
+    Overworld,
+    Nether,
+    End,
+}
+
+impl Dimension {
+    /// Overworld blocks covered by one block of this dimension along x and z.
+    pub fn coordinate_scale(&self) -> f64 {
+        match self {
+            Dimension::Nether => NETHER_COORDINATE_SCALE,
+            Dimension::Overworld | Dimension::End => 1.0,
+        }
+    }
+
+    /// Maps a position into `target` the way nether portals do: x and z are scaled, y is kept.
+    /// The End has no scaled counterpart, so it converts one to one.
+    pub fn convert(&self, position: Space3D, target: Dimension) -> Space3D {
+        let factor = self.coordinate_scale() / target.coordinate_scale();
+        Space3D::new(position.x * factor, position.y, position.z * factor)
+    }
+
+    pub fn settings(&self) -> DimensionSettings {
+        match self {
+            Dimension::Overworld => DimensionSettings::overworld(),
+            Dimension::Nether => DimensionSettings::nether(),
+            Dimension::End => DimensionSettings::end(),
+        }
+    }
+}
+
+/// Vertical limits of a dimension. `max_y` is the exclusive top of the build range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DimensionSettings {
+    pub min_y: i32,
+    pub max_y: i32,
+    /// How far below `min_y` entities survive before vanilla discards them.
+    pub void_kill_depth: f64,
+    /// Adds the bedrock roof at Y 127-128 as a world-wide collision slab.
+    pub bedrock_ceiling: bool,
+}
+
+impl DimensionSettings {
+    pub fn overworld() -> Self {
+        Self {
+            min_y: -64,
+            max_y: 320,
+            void_kill_depth: VOID_KILL_DEPTH,
+            bedrock_ceiling: false,
+        }
+    }
+
+    pub fn nether() -> Self {
+        Self {
+            min_y: 0,
+            max_y: 256,
+            void_kill_depth: VOID_KILL_DEPTH,
+            bedrock_ceiling: true,
+        }
+    }
+
+    pub fn end() -> Self {
+        Self {
+            min_y: 0,
+            max_y: 256,
+            void_kill_depth: VOID_KILL_DEPTH,
+            bedrock_ceiling: false,
+        }
+    }
+
+    /// Entities whose feet are below this at the start of a tick are removed.
+    pub fn void_kill_y(&self) -> f64 {
+        self.min_y as f64 - self.void_kill_depth
+    }
+
+    pub fn ceiling_box(&self) -> Option<AABBBox> {
+        self.bedrock_ceiling.then(|| {
+            AABBBox::new(
+                -WORLD_BORDER_EXTENT,
+                NETHER_CEILING_Y,
+                -WORLD_BORDER_EXTENT,
+                WORLD_BORDER_EXTENT,
+                NETHER_CEILING_Y + 1.0,
+                WORLD_BORDER_EXTENT,
+            )
+        })
+    }
+}
+
+impl Default for DimensionSettings {
+    fn default() -> Self {
+        Self::overworld()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FluidKind {
+    Water,
+    Lava,
+}
+
+/// A volume of fluid whose top is the fluid surface, e.g. `y + 8/9` for a source block
+/// without fluid above it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FluidRegion {
+    pub bounds: AABBBox,
+    pub kind: FluidKind,
+}
+
+impl FluidRegion {
+    pub fn new(bounds: AABBBox, kind: FluidKind) -> Self {
+        Self { bounds, kind }
+    }
+}
@@ -11,3 +11,28 @@ pub enum LayoutDirection {
     West,
     East,
 }
+
+impl LayoutDirection {
+    pub fn is_cardinal(&self) -> bool {
+        matches!(
+            self,
+            LayoutDirection::North
+                | LayoutDirection::South
+                | LayoutDirection::West
+                | LayoutDirection::East
+        )
+    }
+
+    /// Whether red and blue dupers placed here can steer a pearl in every direction: both in
+    /// corners, or both on sides of different axes. Anything else leaves red and blue pushing
+    /// along the same line for some flight directions.
+    pub fn pairs_with(&self, other: LayoutDirection) -> bool {
+        let on_north_south =
+            |dir: &LayoutDirection| matches!(dir, LayoutDirection::North | LayoutDirection::South);
+        match (self.is_cardinal(), other.is_cardinal()) {
+            (false, false) => true,
+            (true, true) => on_north_south(self) != on_north_south(&other),
+            _ => false,
+        }
+    }
+}
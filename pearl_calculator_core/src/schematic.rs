@@ -0,0 +1,9 @@
+pub mod anvil;
+pub mod block_shapes;
+pub mod block_state;
+pub mod litematic;
+pub mod nbt;
+pub mod packed_array;
+pub mod schematic;
+pub mod sponge;
+pub mod structure;
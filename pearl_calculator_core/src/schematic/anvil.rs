@@ -0,0 +1,621 @@
+use crate::physics::world::chunk::{ChunkPos, for_each_chunk_on_segment};
+use crate::physics::world::space::Space3D;
+use crate::schematic::block_state::BlockState;
+use crate::schematic::nbt::{MAX_DECOMPRESSED_LEN, NbtTag, decompress, read_nbt};
+use crate::schematic::packed_array::{bits_for_palette, read_aligned, read_spanning};
+use crate::schematic::schematic::{BlockPos, Schematic, SchematicBlock};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+const SECTOR_SIZE: usize = 4096;
+
+/// First data version (20w17a) whose block states no longer straddle longs.
+const ALIGNED_BLOCK_STATES_DATA_VERSION: i64 = 2529;
+
+/// lz4-java never writes blocks larger than 32 MiB, so anything above comes from a corrupt
+/// header.
+const LZ4_MAX_BLOCK_LEN: usize = 1 << 25;
+
+/// Vertical block range, inclusive, that a corridor needs inside one chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkSpan {
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+impl ChunkSpan {
+    fn union(self, other: ChunkSpan) -> ChunkSpan {
+        ChunkSpan {
+            min_y: self.min_y.min(other.min_y),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    fn contains(&self, y: i32) -> bool {
+        (self.min_y..=self.max_y).contains(&y)
+    }
+}
+
+/// Chunks a trace (in world coordinates, e.g. `CalculationResult::pearl_trace`) passes
+/// through, each with the height band it crosses. Every chunk within `horizontal_margin`
+/// chunks is included, and bands are widened by `vertical_margin` blocks.
+pub fn trace_corridor(
+    trace: &[Space3D],
+    horizontal_margin: i32,
+    vertical_margin: i32,
+) -> BTreeMap<ChunkPos, ChunkSpan> {
+    let mut crossed: BTreeMap<ChunkPos, ChunkSpan> = BTreeMap::new();
+    let mut add = |chunk: ChunkPos, y0: f64, y1: f64| {
+        let span = ChunkSpan {
+            min_y: y0.min(y1).floor() as i32 - vertical_margin,
+            max_y: y0.max(y1).floor() as i32 + vertical_margin,
+        };
+        crossed
+            .entry(chunk)
+            .and_modify(|existing| *existing = existing.union(span))
+            .or_insert(span);
+    };
+
+    match trace {
+        [] => {}
+        [point] => add(ChunkPos::from_block(point.x, point.z), point.y, point.y),
+        _ => {
+            for segment in trace.windows(2) {
+                let (from, to) = (segment[0], segment[1]);
+                for_each_chunk_on_segment(from, to, |chunk, t0, t1| {
+                    add(
+                        chunk,
+                        from.y + (to.y - from.y) * t0,
+                        from.y + (to.y - from.y) * t1,
+                    )
+                });
+            }
+        }
+    }
+
+    let mut corridor: BTreeMap<ChunkPos, ChunkSpan> = BTreeMap::new();
+    for (chunk, span) in crossed {
+        for dx in -horizontal_margin..=horizontal_margin {
+            for dz in -horizontal_margin..=horizontal_margin {
+                corridor
+                    .entry(ChunkPos::new(chunk.x + dx, chunk.z + dz))
+                    .and_modify(|existing| *existing = existing.union(span))
+                    .or_insert(span);
+            }
+        }
+    }
+    corridor
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CorridorBlocks {
+    /// Blocks in world coordinates, ready for `Schematic::to_collision_world`.
+    pub schematic: Schematic,
+    /// Corridor chunks the save has never generated.
+    pub missing_chunks: Vec<ChunkPos>,
+}
+
+/// A dimension of a world save, read straight from its `region/*.mca` files.
+pub struct AnvilWorld {
+    region_dir: PathBuf,
+}
+
+impl AnvilWorld {
+    /// Accepts a dimension folder (the world folder, `DIM-1`, `DIM1`, ...) or its
+    /// `region` folder.
+    pub fn open(path: &Path) -> Self {
+        let region_dir = path.join("region");
+        Self {
+            region_dir: if region_dir.is_dir() {
+                region_dir
+            } else {
+                path.to_path_buf()
+            },
+        }
+    }
+
+    pub fn load_corridor(
+        &self,
+        corridor: &BTreeMap<ChunkPos, ChunkSpan>,
+    ) -> Result<CorridorBlocks, Box<dyn Error>> {
+        let mut by_region: BTreeMap<(i32, i32), Vec<(ChunkPos, ChunkSpan)>> = BTreeMap::new();
+        for (&chunk, &span) in corridor {
+            by_region
+                .entry(chunk.region())
+                .or_default()
+                .push((chunk, span));
+        }
+
+        let mut builder = SchematicBuilder::default();
+        let mut missing_chunks = Vec::new();
+
+        for ((rx, rz), chunks) in by_region {
+            let path = self.region_dir.join(format!("r.{rx}.{rz}.mca"));
+            let region = match std::fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    missing_chunks.extend(chunks.iter().map(|(chunk, _)| *chunk));
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            for (chunk, span) in chunks {
+                let nbt = self
+                    .read_chunk_from_region(&region, chunk)
+                    .map_err(|e| format!("Chunk {}, {}: {e}", chunk.x, chunk.z))?;
+                match nbt {
+                    Some(nbt) => read_chunk_blocks(&nbt, chunk, span, &mut builder)
+                        .map_err(|e| format!("Chunk {}, {}: {e}", chunk.x, chunk.z))?,
+                    None => missing_chunks.push(chunk),
+                }
+            }
+        }
+
+        Ok(CorridorBlocks {
+            schematic: builder.schematic,
+            missing_chunks,
+        })
+    }
+
+    pub fn read_chunk(&self, chunk: ChunkPos) -> Result<Option<NbtTag>, Box<dyn Error>> {
+        let (rx, rz) = chunk.region();
+        let path = self.region_dir.join(format!("r.{rx}.{rz}.mca"));
+        match std::fs::read(&path) {
+            Ok(region) => self.read_chunk_from_region(&region, chunk),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn read_chunk_from_region(
+        &self,
+        region: &[u8],
+        chunk: ChunkPos,
+    ) -> Result<Option<NbtTag>, Box<dyn Error>> {
+        let index = ((chunk.x & 31) + (chunk.z & 31) * 32) as usize * 4;
+        let location = region
+            .get(index..index + 4)
+            .ok_or("region header is truncated")?;
+        let sector = u32::from_be_bytes([0, location[0], location[1], location[2]]) as usize;
+        if sector == 0 {
+            return Ok(None);
+        }
+
+        let start = sector * SECTOR_SIZE;
+        let header = region
+            .get(start..start + 5)
+            .ok_or("chunk points past the end of the region")?;
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let compression = header[4];
+        if length == 0 {
+            return Ok(None);
+        }
+
+        // The high bit moves oversized chunks into a separate `c.x.z.mcc` file.
+        let external;
+        let payload = if compression & 0x80 != 0 {
+            external = std::fs::read(
+                self.region_dir
+                    .join(format!("c.{}.{}.mcc", chunk.x, chunk.z)),
+            )?;
+            &external[..]
+        } else {
+            region
+                .get(start + 5..start + 4 + length)
+                .ok_or("chunk data is truncated")?
+        };
+
+        let data = match compression & 0x7F {
+            1 | 2 => decompress(payload)?,
+            3 => payload.to_vec(),
+            4 => decompress_lz4_blocks(payload)?,
+            other => return Err(format!("unsupported chunk compression {other}").into()),
+        };
+
+        Ok(Some(read_nbt(&data)?.1))
+    }
+}
+
+/// Minecraft's LZ4 chunks use lz4-java's block stream: a sequence of `LZ4Block` headers,
+/// each followed by a raw or LZ4 block, ending with an empty block.
+fn decompress_lz4_blocks(mut data: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    const HEADER_LEN: usize = 21;
+    let mut out = Vec::new();
+
+    while data.len() >= HEADER_LEN {
+        if &data[..8] != b"LZ4Block" {
+            return Err("invalid LZ4 block magic".into());
+        }
+        let method = data[8] & 0xF0;
+        // Java writes the lengths as signed ints; negative ones end up above the cap.
+        let read_len = |at: usize| {
+            u32::from_le_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]]) as usize
+        };
+        let compressed_len = read_len(9);
+        let decompressed_len = read_len(13);
+        if decompressed_len == 0 {
+            break;
+        }
+        if compressed_len > LZ4_MAX_BLOCK_LEN || decompressed_len > LZ4_MAX_BLOCK_LEN {
+            return Err("LZ4 block length is out of range".into());
+        }
+
+        let block_end = HEADER_LEN
+            .checked_add(compressed_len)
+            .ok_or("LZ4 block length is out of range")?;
+        let block = data
+            .get(HEADER_LEN..block_end)
+            .ok_or("LZ4 block is truncated")?;
+        match method {
+            0x10 => out.extend_from_slice(block),
+            0x20 => out.extend(lz4_flex::block::decompress(block, decompressed_len)?),
+            other => return Err(format!("unsupported LZ4 block method {other:#x}").into()),
+        }
+        if out.len() > MAX_DECOMPRESSED_LEN {
+            return Err("LZ4 chunk decompresses past the size limit".into());
+        }
+        data = &data[block_end..];
+    }
+
+    Ok(out)
+}
+
+fn read_chunk_blocks(
+    chunk_nbt: &NbtTag,
+    chunk: ChunkPos,
+    span: ChunkSpan,
+    builder: &mut SchematicBuilder,
+) -> Result<(), Box<dyn Error>> {
+    let data_version = chunk_nbt
+        .get("DataVersion")
+        .and_then(NbtTag::as_i64)
+        .unwrap_or(0);
+    let aligned = data_version >= ALIGNED_BLOCK_STATES_DATA_VERSION;
+
+    // 1.18 flattened the chunk root and moved each section's states into `block_states`.
+    let (sections, legacy_layout) = match chunk_nbt.get("Level") {
+        Some(level) => (level.get("Sections"), true),
+        None => (chunk_nbt.get("sections"), false),
+    };
+    let Some(sections) = sections.and_then(NbtTag::as_list) else {
+        return Ok(());
+    };
+
+    for section in sections {
+        let Some(section_y) = section.get("Y").and_then(NbtTag::as_i64) else {
+            continue;
+        };
+        let base_y = section_y as i32 * 16;
+        if base_y + 15 < span.min_y || base_y > span.max_y {
+            continue;
+        }
+
+        let (palette_tag, states_tag) = if legacy_layout {
+            if section.get("Blocks").is_some() && section.get("Palette").is_none() {
+                return Err("chunks saved before 1.13 are not supported".into());
+            }
+            (section.get("Palette"), section.get("BlockStates"))
+        } else {
+            let block_states = section.get("block_states");
+            (
+                block_states.and_then(|b| b.get("palette")),
+                block_states.and_then(|b| b.get("data")),
+            )
+        };
+
+        let Some(palette_list) = palette_tag.and_then(NbtTag::as_list) else {
+            continue;
+        };
+        let palette: Vec<BlockState> = palette_list
+            .iter()
+            .map(|entry| BlockState::from_nbt(entry).ok_or("invalid palette entry"))
+            .collect::<Result<_, _>>()?;
+        if palette.iter().all(BlockState::is_empty) {
+            continue;
+        }
+        let palette_ids: Vec<usize> = palette.iter().map(|state| builder.intern(state)).collect();
+
+        let states: &[i64] = match states_tag {
+            Some(NbtTag::LongArray(longs)) => longs,
+            _ => &[],
+        };
+        let bits = bits_for_palette(palette.len(), 4);
+
+        for index in 0..4096 {
+            let y = base_y + (index >> 8) as i32;
+            if !span.contains(y) {
+                continue;
+            }
+
+            // A single-entry palette may omit its data entirely.
+            let state = if palette.len() == 1 {
+                0
+            } else if aligned {
+                read_aligned(states, index, bits).ok_or("block states ended early")?
+            } else {
+                read_spanning(states, index, bits).ok_or("block states ended early")?
+            };
+            let block = palette
+                .get(state)
+                .ok_or("block state outside the palette")?;
+            if block.is_empty() {
+                continue;
+            }
+
+            builder.schematic.blocks.push(SchematicBlock {
+                pos: BlockPos::new(
+                    chunk.x * 16 + (index & 15) as i32,
+                    y,
+                    chunk.z * 16 + ((index >> 4) & 15) as i32,
+                ),
+                state: palette_ids[state],
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Shares one palette across all chunks instead of repeating every section's.
+#[derive(Default)]
+struct SchematicBuilder {
+    schematic: Schematic,
+    ids: HashMap<BlockState, usize>,
+}
+
+impl SchematicBuilder {
+    fn intern(&mut self, state: &BlockState) -> usize {
+        if let Some(&id) = self.ids.get(state) {
+            return id;
+        }
+        let id = self.schematic.palette.len();
+        self.schematic.palette.push(state.clone());
+        self.ids.insert(state.clone(), id);
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schematic::packed_array::tests::pack_spanning;
+    use flate2::Compression;
+    use flate2::write::ZlibEncoder;
+    use std::io::Write;
+
+    fn compound(entries: Vec<(&str, NbtTag)>) -> NbtTag {
+        NbtTag::Compound(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    fn tag_type(tag: &NbtTag) -> u8 {
+        match tag {
+            NbtTag::Byte(_) => 1,
+            NbtTag::Short(_) => 2,
+            NbtTag::Int(_) => 3,
+            NbtTag::Long(_) => 4,
+            NbtTag::Float(_) => 5,
+            NbtTag::Double(_) => 6,
+            NbtTag::ByteArray(_) => 7,
+            NbtTag::String(_) => 8,
+            NbtTag::List(_) => 9,
+            NbtTag::Compound(_) => 10,
+            NbtTag::IntArray(_) => 11,
+            NbtTag::LongArray(_) => 12,
+        }
+    }
+
+    /// Just enough of an NBT writer for the tags these tests build.
+    fn write_payload(tag: &NbtTag, out: &mut Vec<u8>) {
+        let write_str = |s: &str, out: &mut Vec<u8>| {
+            out.extend((s.len() as u16).to_be_bytes());
+            out.extend(s.as_bytes());
+        };
+        match tag {
+            NbtTag::Int(v) => out.extend(v.to_be_bytes()),
+            NbtTag::String(v) => write_str(v, out),
+            NbtTag::List(items) => {
+                out.push(items.first().map_or(0, tag_type));
+                out.extend((items.len() as i32).to_be_bytes());
+                items.iter().for_each(|item| write_payload(item, out));
+            }
+            NbtTag::Compound(map) => {
+                for (key, value) in map {
+                    out.push(tag_type(value));
+                    write_str(key, out);
+                    write_payload(value, out);
+                }
+                out.push(0);
+            }
+            NbtTag::LongArray(longs) => {
+                out.extend((longs.len() as i32).to_be_bytes());
+                longs.iter().for_each(|long| out.extend(long.to_be_bytes()));
+            }
+            other => unimplemented!("{other:?}"),
+        }
+    }
+
+    fn write_nbt(root: &NbtTag) -> Vec<u8> {
+        let mut out = vec![10, 0, 0];
+        write_payload(root, &mut out);
+        out
+    }
+
+    /// Seventeen states, so block states take five bits and the layouts differ.
+    fn palette() -> Vec<NbtTag> {
+        std::iter::once("minecraft:air".to_string())
+            .chain((1..17).map(|i| format!("minecraft:block_{i}")))
+            .map(|name| compound(vec![("Name", NbtTag::String(name))]))
+            .collect()
+    }
+
+    fn states() -> Vec<usize> {
+        (0..4096).map(|index| (index * 5) % 17).collect()
+    }
+
+    fn pack_aligned(values: &[usize], bits: usize) -> Vec<i64> {
+        values
+            .chunks(64 / bits)
+            .map(|chunk| {
+                chunk.iter().enumerate().fold(0u64, |long, (i, &value)| {
+                    long | (value as u64) << (i * bits)
+                }) as i64
+            })
+            .collect()
+    }
+
+    fn modern_chunk() -> NbtTag {
+        let section = compound(vec![
+            ("Y", NbtTag::Int(-1)),
+            (
+                "block_states",
+                compound(vec![
+                    ("palette", NbtTag::List(palette())),
+                    ("data", NbtTag::LongArray(pack_aligned(&states(), 5))),
+                ]),
+            ),
+        ]);
+        compound(vec![
+            ("DataVersion", NbtTag::Int(3465)),
+            ("sections", NbtTag::List(vec![section])),
+        ])
+    }
+
+    fn legacy_chunk() -> NbtTag {
+        let section = compound(vec![
+            ("Y", NbtTag::Int(-1)),
+            ("Palette", NbtTag::List(palette())),
+            (
+                "BlockStates",
+                NbtTag::LongArray(pack_spanning(&states(), 5)),
+            ),
+        ]);
+        compound(vec![
+            ("DataVersion", NbtTag::Int(1976)),
+            (
+                "Level",
+                compound(vec![("Sections", NbtTag::List(vec![section]))]),
+            ),
+        ])
+    }
+
+    /// A region with `payload` stored for `chunk` in sector 2.
+    fn region_with(chunk: ChunkPos, compression: u8, payload: &[u8]) -> Vec<u8> {
+        let mut region = vec![0; 2 * SECTOR_SIZE];
+        let index = ((chunk.x & 31) + (chunk.z & 31) * 32) as usize * 4;
+        let sectors = (payload.len() + 5).div_ceil(SECTOR_SIZE) as u8;
+        region[index..index + 4].copy_from_slice(&[0, 0, 2, sectors]);
+        region.extend(((payload.len() + 1) as u32).to_be_bytes());
+        region.push(compression);
+        region.extend(payload);
+        region.resize((2 + sectors as usize) * SECTOR_SIZE, 0);
+        region
+    }
+
+    fn lz4_block(method: u8, block: &[u8], decompressed_len: usize) -> Vec<u8> {
+        let mut out = b"LZ4Block".to_vec();
+        out.push(method);
+        out.extend((block.len() as u32).to_le_bytes());
+        out.extend((decompressed_len as u32).to_le_bytes());
+        out.extend([0; 4]);
+        out.extend(block);
+        out
+    }
+
+    fn world() -> AnvilWorld {
+        AnvilWorld {
+            region_dir: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn reads_chunks_in_every_compression() {
+        let chunk = ChunkPos { x: -31, z: 34 };
+        let nbt = write_nbt(&modern_chunk());
+
+        let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+        zlib.write_all(&nbt).unwrap();
+        let (first, second) = nbt.split_at(nbt.len() / 2);
+        let mut lz4 = lz4_block(0x10, first, first.len());
+        let compressed = lz4_flex::block::compress(second);
+        lz4.extend(lz4_block(0x20, &compressed, second.len()));
+        lz4.extend(lz4_block(0x10, &[], 0));
+
+        for (compression, payload) in [(2, zlib.finish().unwrap()), (3, nbt.clone()), (4, lz4)] {
+            let region = region_with(chunk, compression, &payload);
+            let read = world().read_chunk_from_region(&region, chunk).unwrap();
+            assert_eq!(read, Some(modern_chunk()), "compression {compression}");
+            let other = ChunkPos { x: -30, z: 34 };
+            assert_eq!(
+                world().read_chunk_from_region(&region, other).unwrap(),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_regions_and_bad_lz4_lengths() {
+        let chunk = ChunkPos { x: 3, z: 0 };
+        let region = region_with(chunk, 3, &write_nbt(&modern_chunk()));
+
+        assert!(world().read_chunk_from_region(&region[..8], chunk).is_err());
+        assert!(
+            world()
+                .read_chunk_from_region(&region[..SECTOR_SIZE * 2 + 2], chunk)
+                .is_err()
+        );
+        assert!(
+            world()
+                .read_chunk_from_region(&region[..SECTOR_SIZE * 2 + 100], chunk)
+                .is_err()
+        );
+
+        let mut oversized = lz4_block(0x10, &[1, 2, 3], 3);
+        oversized[13..17].copy_from_slice(&(-1i32).to_le_bytes());
+        assert!(decompress_lz4_blocks(&oversized).is_err());
+
+        let mut truncated = lz4_block(0x10, &[1, 2, 3], 3);
+        truncated.truncate(22);
+        assert!(decompress_lz4_blocks(&truncated).is_err());
+    }
+
+    #[test]
+    fn reads_aligned_and_spanning_block_states() {
+        let chunk = ChunkPos { x: 2, z: -1 };
+        let span = ChunkSpan {
+            min_y: -16,
+            max_y: -1,
+        };
+        let expected: Vec<(BlockPos, String)> = states()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, state)| state != 0)
+            .map(|(index, state)| {
+                let pos = BlockPos::new(
+                    32 + (index & 15) as i32,
+                    -16 + (index >> 8) as i32,
+                    -16 + ((index >> 4) & 15) as i32,
+                );
+                (pos, format!("minecraft:block_{state}"))
+            })
+            .collect();
+
+        for chunk_nbt in [modern_chunk(), legacy_chunk()] {
+            let mut builder = SchematicBuilder::default();
+            read_chunk_blocks(&chunk_nbt, chunk, span, &mut builder).unwrap();
+            let schematic = builder.schematic;
+            let blocks: Vec<(BlockPos, String)> = schematic
+                .blocks
+                .iter()
+                .map(|block| (block.pos, schematic.palette[block.state].name.clone()))
+                .collect();
+            assert_eq!(blocks, expected);
+        }
+    }
+}
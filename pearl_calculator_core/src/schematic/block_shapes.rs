@@ -0,0 +1,551 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::entities::movement::PearlVersion;
+use crate::schematic::block_state::BlockState;
+
+/// How a block name is matched against the table, on the id without its namespace.
+#[derive(Debug, Clone, Copy)]
+pub enum BlockPattern {
+    Exact(&'static str),
+    Prefix(&'static str),
+    Suffix(&'static str),
+}
+
+impl BlockPattern {
+    fn matches(&self, id: &str) -> bool {
+        match self {
+            BlockPattern::Exact(name) => id == *name,
+            BlockPattern::Prefix(prefix) => id.starts_with(prefix),
+            BlockPattern::Suffix(suffix) => id.ends_with(suffix),
+        }
+    }
+}
+
+/// Collision shape of a family of blocks. Fixed boxes are in pixels (1/16 block); the
+/// other kinds read the block state's properties.
+#[derive(Debug, Clone, Copy)]
+pub enum ShapeKind {
+    Empty,
+    Full,
+    Boxes(&'static [[f64; 6]]),
+    Slab,
+    Stairs,
+    Wall,
+    Pane,
+    Fence,
+    FenceGate,
+    Trapdoor,
+    Door,
+    Ladder,
+    SnowLayers,
+    Scaffolding,
+    /// Pale moss carpet only collides on its bottom layer; side layers have no box.
+    BottomCarpet,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ShapeRule {
+    pub pattern: BlockPattern,
+    /// First version the rule applies to. The first matching row wins, so a row for newer
+    /// versions must come before the general row for the same block.
+    pub since: PearlVersion,
+    pub shape: ShapeKind,
+}
+
+const fn rule(pattern: BlockPattern, shape: ShapeKind) -> ShapeRule {
+    ShapeRule {
+        pattern,
+        since: PearlVersion::Legacy,
+        shape,
+    }
+}
+
+use BlockPattern::{Exact, Prefix, Suffix};
+
+/// Blocks that are not full cubes, checked in order. Anything unlisted is a full cube.
+///
+/// Pearls raycast against collision shapes and ignore fluids, so fluids and plants are
+/// empty even though they have outlines.
+pub const SHAPE_TABLE: &[ShapeRule] = &[
+    // Before the flower rules, which would otherwise match potted flowers.
+    rule(
+        Prefix("potted_"),
+        ShapeKind::Boxes(&[[5.0, 0.0, 5.0, 11.0, 6.0, 11.0]]),
+    ),
+    rule(
+        Exact("flower_pot"),
+        ShapeKind::Boxes(&[[5.0, 0.0, 5.0, 11.0, 6.0, 11.0]]),
+    ),
+    rule(Exact("water"), ShapeKind::Empty),
+    rule(Exact("lava"), ShapeKind::Empty),
+    rule(Exact("bubble_column"), ShapeKind::Empty),
+    rule(Exact("light"), ShapeKind::Empty),
+    rule(Exact("fire"), ShapeKind::Empty),
+    rule(Exact("soul_fire"), ShapeKind::Empty),
+    rule(Exact("nether_portal"), ShapeKind::Empty),
+    rule(Exact("end_portal"), ShapeKind::Empty),
+    rule(Exact("end_gateway"), ShapeKind::Empty),
+    rule(Exact("cobweb"), ShapeKind::Empty),
+    rule(Exact("redstone_wire"), ShapeKind::Empty),
+    rule(Exact("lever"), ShapeKind::Empty),
+    rule(Exact("tripwire"), ShapeKind::Empty),
+    rule(Exact("tripwire_hook"), ShapeKind::Empty),
+    rule(Exact("rail"), ShapeKind::Empty),
+    rule(Suffix("_rail"), ShapeKind::Empty),
+    rule(Suffix("torch"), ShapeKind::Empty),
+    rule(Suffix("_button"), ShapeKind::Empty),
+    rule(Suffix("_pressure_plate"), ShapeKind::Empty),
+    rule(Suffix("_sign"), ShapeKind::Empty),
+    rule(Suffix("_banner"), ShapeKind::Empty),
+    rule(Suffix("_sapling"), ShapeKind::Empty),
+    rule(Exact("short_grass"), ShapeKind::Empty),
+    rule(Exact("grass"), ShapeKind::Empty),
+    rule(Exact("tall_grass"), ShapeKind::Empty),
+    rule(Exact("fern"), ShapeKind::Empty),
+    rule(Exact("large_fern"), ShapeKind::Empty),
+    rule(Exact("dead_bush"), ShapeKind::Empty),
+    rule(Exact("vine"), ShapeKind::Empty),
+    rule(Suffix("_vines"), ShapeKind::Empty),
+    rule(Suffix("_vines_plant"), ShapeKind::Empty),
+    rule(Exact("glow_lichen"), ShapeKind::Empty),
+    rule(Exact("sculk_vein"), ShapeKind::Empty),
+    rule(Exact("hanging_roots"), ShapeKind::Empty),
+    rule(Exact("spore_blossom"), ShapeKind::Empty),
+    rule(Exact("sugar_cane"), ShapeKind::Empty),
+    rule(Exact("small_dripleaf"), ShapeKind::Empty),
+    rule(Exact("big_dripleaf_stem"), ShapeKind::Empty),
+    rule(Exact("mangrove_propagule"), ShapeKind::Empty),
+    rule(Exact("sweet_berry_bush"), ShapeKind::Empty),
+    rule(Exact("bush"), ShapeKind::Empty),
+    rule(Exact("firefly_bush"), ShapeKind::Empty),
+    rule(Exact("short_dry_grass"), ShapeKind::Empty),
+    rule(Exact("tall_dry_grass"), ShapeKind::Empty),
+    // Water plants.
+    rule(Exact("kelp"), ShapeKind::Empty),
+    rule(Exact("kelp_plant"), ShapeKind::Empty),
+    rule(Exact("seagrass"), ShapeKind::Empty),
+    rule(Exact("tall_seagrass"), ShapeKind::Empty),
+    rule(Suffix("_coral"), ShapeKind::Empty),
+    rule(Suffix("_coral_fan"), ShapeKind::Empty),
+    rule(Suffix("_coral_wall_fan"), ShapeKind::Empty),
+    // Flowers.
+    rule(Exact("dandelion"), ShapeKind::Empty),
+    rule(Exact("poppy"), ShapeKind::Empty),
+    rule(Exact("blue_orchid"), ShapeKind::Empty),
+    rule(Exact("allium"), ShapeKind::Empty),
+    rule(Exact("azure_bluet"), ShapeKind::Empty),
+    rule(Suffix("_tulip"), ShapeKind::Empty),
+    rule(Exact("oxeye_daisy"), ShapeKind::Empty),
+    rule(Exact("cornflower"), ShapeKind::Empty),
+    rule(Exact("lily_of_the_valley"), ShapeKind::Empty),
+    rule(Exact("wither_rose"), ShapeKind::Empty),
+    rule(Exact("torchflower"), ShapeKind::Empty),
+    rule(Exact("closed_eyeblossom"), ShapeKind::Empty),
+    rule(Exact("open_eyeblossom"), ShapeKind::Empty),
+    rule(Exact("sunflower"), ShapeKind::Empty),
+    rule(Exact("lilac"), ShapeKind::Empty),
+    rule(Exact("rose_bush"), ShapeKind::Empty),
+    rule(Exact("peony"), ShapeKind::Empty),
+    rule(Exact("pitcher_plant"), ShapeKind::Empty),
+    rule(Exact("pink_petals"), ShapeKind::Empty),
+    rule(Exact("wildflowers"), ShapeKind::Empty),
+    rule(Exact("leaf_litter"), ShapeKind::Empty),
+    rule(Exact("cactus_flower"), ShapeKind::Empty),
+    // Crops.
+    rule(Exact("wheat"), ShapeKind::Empty),
+    rule(Exact("carrots"), ShapeKind::Empty),
+    rule(Exact("potatoes"), ShapeKind::Empty),
+    rule(Exact("beetroots"), ShapeKind::Empty),
+    rule(Exact("melon_stem"), ShapeKind::Empty),
+    rule(Exact("pumpkin_stem"), ShapeKind::Empty),
+    rule(Exact("attached_melon_stem"), ShapeKind::Empty),
+    rule(Exact("attached_pumpkin_stem"), ShapeKind::Empty),
+    rule(Exact("torchflower_crop"), ShapeKind::Empty),
+    rule(Exact("pitcher_crop"), ShapeKind::Empty),
+    rule(Exact("nether_wart"), ShapeKind::Empty),
+    // Mushrooms and nether plants.
+    rule(Exact("brown_mushroom"), ShapeKind::Empty),
+    rule(Exact("red_mushroom"), ShapeKind::Empty),
+    rule(Exact("crimson_fungus"), ShapeKind::Empty),
+    rule(Exact("warped_fungus"), ShapeKind::Empty),
+    rule(Exact("crimson_roots"), ShapeKind::Empty),
+    rule(Exact("warped_roots"), ShapeKind::Empty),
+    rule(Exact("nether_sprouts"), ShapeKind::Empty),
+    rule(Suffix("_slab"), ShapeKind::Slab),
+    rule(Suffix("_stairs"), ShapeKind::Stairs),
+    rule(Suffix("_wall"), ShapeKind::Wall),
+    rule(Exact("glass_pane"), ShapeKind::Pane),
+    rule(Suffix("_pane"), ShapeKind::Pane),
+    rule(Exact("iron_bars"), ShapeKind::Pane),
+    rule(Suffix("_fence_gate"), ShapeKind::FenceGate),
+    rule(Suffix("_fence"), ShapeKind::Fence),
+    rule(Suffix("_trapdoor"), ShapeKind::Trapdoor),
+    rule(Suffix("_door"), ShapeKind::Door),
+    rule(Exact("ladder"), ShapeKind::Ladder),
+    rule(
+        Exact("lily_pad"),
+        ShapeKind::Boxes(&[[1.0, 0.0, 1.0, 15.0, 1.5, 15.0]]),
+    ),
+    ShapeRule {
+        pattern: Exact("pale_moss_carpet"),
+        since: PearlVersion::Post1212,
+        shape: ShapeKind::BottomCarpet,
+    },
+    rule(
+        Suffix("carpet"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 1.0, 16.0]]),
+    ),
+    rule(Exact("scaffolding"), ShapeKind::Scaffolding),
+    rule(Exact("snow"), ShapeKind::SnowLayers),
+    rule(
+        Exact("repeater"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 2.0, 16.0]]),
+    ),
+    rule(
+        Exact("comparator"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 2.0, 16.0]]),
+    ),
+    rule(
+        Exact("daylight_detector"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 6.0, 16.0]]),
+    ),
+    rule(
+        Exact("farmland"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 15.0, 16.0]]),
+    ),
+    rule(
+        Exact("dirt_path"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 15.0, 16.0]]),
+    ),
+    rule(
+        Exact("soul_sand"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 14.0, 16.0]]),
+    ),
+    rule(
+        Exact("enchanting_table"),
+        ShapeKind::Boxes(&[[0.0, 0.0, 0.0, 16.0, 12.0, 16.0]]),
+    ),
+    rule(
+        Exact("cactus"),
+        ShapeKind::Boxes(&[[1.0, 0.0, 1.0, 15.0, 15.0, 15.0]]),
+    ),
+    rule(
+        Exact("chest"),
+        ShapeKind::Boxes(&[[1.0, 0.0, 1.0, 15.0, 14.0, 15.0]]),
+    ),
+    rule(
+        Exact("trapped_chest"),
+        ShapeKind::Boxes(&[[1.0, 0.0, 1.0, 15.0, 14.0, 15.0]]),
+    ),
+    rule(
+        Exact("ender_chest"),
+        ShapeKind::Boxes(&[[1.0, 0.0, 1.0, 15.0, 14.0, 15.0]]),
+    ),
+];
+
+/// Collision boxes of a block state in block-local coordinates (0 to 1).
+pub fn collision_shape(state: &BlockState, version: PearlVersion) -> Vec<AABBBox> {
+    let id = state.id();
+    let shape = SHAPE_TABLE
+        .iter()
+        .find(|rule| rule.since <= version && rule.pattern.matches(id))
+        .map_or(ShapeKind::Full, |rule| rule.shape);
+
+    let pixels = match shape {
+        ShapeKind::Empty => Vec::new(),
+        ShapeKind::Full => vec![[0.0, 0.0, 0.0, 16.0, 16.0, 16.0]],
+        ShapeKind::Boxes(boxes) => boxes.to_vec(),
+        ShapeKind::Slab => slab_shape(state),
+        ShapeKind::Stairs => stairs_shape(state),
+        ShapeKind::Wall => cross_shape(state, 4.0, 3.0, 24.0, true),
+        ShapeKind::Pane => cross_shape(state, 1.0, 1.0, 16.0, false),
+        ShapeKind::Fence => cross_shape(state, 2.0, 2.0, 24.0, false),
+        ShapeKind::FenceGate => fence_gate_shape(state),
+        ShapeKind::Trapdoor => trapdoor_shape(state),
+        ShapeKind::Door => door_shape(state),
+        ShapeKind::Ladder => vec![wall_side_box(state.property("facing"))],
+        ShapeKind::SnowLayers => snow_shape(state),
+        // Scaffolding is only solid for entities above it, which a pearl landing on it is.
+        ShapeKind::Scaffolding => vec![[0.0, 14.0, 0.0, 16.0, 16.0, 16.0]],
+        ShapeKind::BottomCarpet => match state.property("bottom") {
+            Some("false") => Vec::new(),
+            _ => vec![[0.0, 0.0, 0.0, 16.0, 1.0, 16.0]],
+        },
+    };
+
+    pixels
+        .into_iter()
+        .map(|[x0, y0, z0, x1, y1, z1]| {
+            AABBBox::new(
+                x0 / 16.0,
+                y0 / 16.0,
+                z0 / 16.0,
+                x1 / 16.0,
+                y1 / 16.0,
+                z1 / 16.0,
+            )
+        })
+        .collect()
+}
+
+fn slab_shape(state: &BlockState) -> Vec<[f64; 6]> {
+    match state.property("type") {
+        Some("top") => vec![[0.0, 8.0, 0.0, 16.0, 16.0, 16.0]],
+        Some("double") => vec![[0.0, 0.0, 0.0, 16.0, 16.0, 16.0]],
+        _ => vec![[0.0, 0.0, 0.0, 16.0, 8.0, 16.0]],
+    }
+}
+
+/// Unit vector of a horizontal `facing` value as (x, z).
+fn facing_vector(facing: Option<&str>) -> (i32, i32) {
+    match facing {
+        Some("south") => (0, 1),
+        Some("west") => (-1, 0),
+        Some("east") => (1, 0),
+        _ => (0, -1),
+    }
+}
+
+/// Half-block x/z range on the side a direction points to.
+fn half_range(component: i32) -> (f64, f64) {
+    match component {
+        1 => (8.0, 16.0),
+        -1 => (0.0, 8.0),
+        _ => (0.0, 16.0),
+    }
+}
+
+fn stairs_shape(state: &BlockState) -> Vec<[f64; 6]> {
+    let top = state.property("half") == Some("top");
+    let (base_y, step_y) = if top {
+        ((8.0, 16.0), (0.0, 8.0))
+    } else {
+        ((0.0, 8.0), (8.0, 16.0))
+    };
+    let mut boxes = vec![[0.0, base_y.0, 0.0, 16.0, base_y.1, 16.0]];
+
+    // The step fills the half towards `facing`. Corner stairs extend it into (inner) or
+    // trim it to (outer) the quarter on the side named by left/right.
+    let (fx, fz) = facing_vector(state.property("facing"));
+    let (left_x, left_z) = (fz, -fx);
+    let (right_x, right_z) = (-fz, fx);
+    let half = |dx: i32, dz: i32| {
+        let (x0, x1) = half_range(dx);
+        let (z0, z1) = half_range(dz);
+        [x0, step_y.0, z0, x1, step_y.1, z1]
+    };
+
+    match state.property("shape") {
+        Some("outer_left") => boxes.push(half(fx + left_x, fz + left_z)),
+        Some("outer_right") => boxes.push(half(fx + right_x, fz + right_z)),
+        Some("inner_left") => {
+            boxes.push(half(fx, fz));
+            boxes.push(half(left_x - fx, left_z - fz));
+        }
+        Some("inner_right") => {
+            boxes.push(half(fx, fz));
+            boxes.push(half(right_x - fx, right_z - fz));
+        }
+        _ => boxes.push(half(fx, fz)),
+    }
+    boxes
+}
+
+/// Whether a side property connects. Walls use none/low/tall since 1.16 and booleans
+/// before, panes and fences always use booleans.
+fn connects(state: &BlockState, side: &str) -> bool {
+    !matches!(state.property(side), None | Some("none") | Some("false"))
+}
+
+/// Panes, fences and walls: a centre post plus an arm towards each connected side.
+fn cross_shape(
+    state: &BlockState,
+    post_half_width: f64,
+    arm_half_width: f64,
+    height: f64,
+    optional_post: bool,
+) -> Vec<[f64; 6]> {
+    let (p0, p1) = (8.0 - post_half_width, 8.0 + post_half_width);
+    let (a0, a1) = (8.0 - arm_half_width, 8.0 + arm_half_width);
+    let mut boxes = Vec::new();
+
+    if !optional_post || state.property("up") != Some("false") {
+        boxes.push([p0, 0.0, p0, p1, height, p1]);
+    }
+    if connects(state, "north") {
+        boxes.push([a0, 0.0, 0.0, a1, height, a1]);
+    }
+    if connects(state, "south") {
+        boxes.push([a0, 0.0, a0, a1, height, 16.0]);
+    }
+    if connects(state, "west") {
+        boxes.push([0.0, 0.0, a0, a1, height, a1]);
+    }
+    if connects(state, "east") {
+        boxes.push([a0, 0.0, a0, 16.0, height, a1]);
+    }
+    boxes
+}
+
+fn fence_gate_shape(state: &BlockState) -> Vec<[f64; 6]> {
+    if state.property("open") == Some("true") {
+        return Vec::new();
+    }
+    match state.property("facing") {
+        Some("east") | Some("west") => vec![[6.0, 0.0, 0.0, 10.0, 24.0, 16.0]],
+        _ => vec![[0.0, 0.0, 6.0, 16.0, 24.0, 10.0]],
+    }
+}
+
+fn trapdoor_shape(state: &BlockState) -> Vec<[f64; 6]> {
+    if state.property("open") != Some("true") {
+        return match state.property("half") {
+            Some("top") => vec![[0.0, 13.0, 0.0, 16.0, 16.0, 16.0]],
+            _ => vec![[0.0, 0.0, 0.0, 16.0, 3.0, 16.0]],
+        };
+    }
+    vec![wall_side_box(state.property("facing"))]
+}
+
+/// A 3 pixel thick panel against the side opposite `facing`, as open trapdoors, ladders and
+/// closed doors are placed.
+fn wall_side_box(facing: Option<&str>) -> [f64; 6] {
+    match facing {
+        Some("south") => [0.0, 0.0, 0.0, 16.0, 16.0, 3.0],
+        Some("west") => [13.0, 0.0, 0.0, 16.0, 16.0, 16.0],
+        Some("east") => [0.0, 0.0, 0.0, 3.0, 16.0, 16.0],
+        _ => [0.0, 0.0, 13.0, 16.0, 16.0, 16.0],
+    }
+}
+
+/// Both halves share one panel. Opening swings it a quarter turn towards the hinge side.
+fn door_shape(state: &BlockState) -> Vec<[f64; 6]> {
+    let facing = state.property("facing");
+    if state.property("open") != Some("true") {
+        return vec![wall_side_box(facing)];
+    }
+    let right_hinge = state.property("hinge") == Some("right");
+    let swung = match (facing, right_hinge) {
+        (Some("south"), true) => "east",
+        (Some("south"), false) => "west",
+        (Some("west"), true) => "south",
+        (Some("west"), false) => "north",
+        (Some("east"), true) => "north",
+        (Some("east"), false) => "south",
+        (_, true) => "west",
+        (_, false) => "east",
+    };
+    vec![wall_side_box(Some(swung))]
+}
+
+/// Snow collides one layer lower than it renders, so a single layer has no box.
+fn snow_shape(state: &BlockState) -> Vec<[f64; 6]> {
+    let layers: f64 = state
+        .property("layers")
+        .and_then(|layers| layers.parse().ok())
+        .unwrap_or(1.0);
+    if layers <= 1.0 {
+        Vec::new()
+    } else {
+        vec![[0.0, 0.0, 0.0, 16.0, (layers - 1.0) * 2.0, 16.0]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(state: &str) -> Vec<AABBBox> {
+        collision_shape(&BlockState::parse(state), PearlVersion::Post1212)
+    }
+
+    fn pixels(boxes: &[[f64; 6]]) -> Vec<AABBBox> {
+        boxes
+            .iter()
+            .map(|b| {
+                AABBBox::new(
+                    b[0] / 16.0,
+                    b[1] / 16.0,
+                    b[2] / 16.0,
+                    b[3] / 16.0,
+                    b[4] / 16.0,
+                    b[5] / 16.0,
+                )
+            })
+            .collect()
+    }
+
+    const BOTTOM: [f64; 6] = [0.0, 0.0, 0.0, 16.0, 8.0, 16.0];
+    const TOP: [f64; 6] = [0.0, 8.0, 0.0, 16.0, 16.0, 16.0];
+
+    #[test]
+    fn straight_stairs_step_towards_facing() {
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=north,half=bottom,shape=straight]"),
+            pixels(&[BOTTOM, [0.0, 8.0, 0.0, 16.0, 16.0, 8.0]])
+        );
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=east,half=top,shape=straight]"),
+            pixels(&[TOP, [8.0, 0.0, 0.0, 16.0, 8.0, 16.0]])
+        );
+    }
+
+    #[test]
+    fn corner_stairs_match_vanilla_quarters() {
+        // Facing north, left is west and right is east.
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=north,half=bottom,shape=outer_left]"),
+            pixels(&[BOTTOM, [0.0, 8.0, 0.0, 8.0, 16.0, 8.0]])
+        );
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=north,half=bottom,shape=outer_right]"),
+            pixels(&[BOTTOM, [8.0, 8.0, 0.0, 16.0, 16.0, 8.0]])
+        );
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=north,half=bottom,shape=inner_left]"),
+            pixels(&[
+                BOTTOM,
+                [0.0, 8.0, 0.0, 16.0, 16.0, 8.0],
+                [0.0, 8.0, 8.0, 8.0, 16.0, 16.0],
+            ])
+        );
+        // Facing south, right is west: the step covers the south half and the north-west
+        // quarter.
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=south,half=top,shape=inner_right]"),
+            pixels(&[
+                TOP,
+                [0.0, 0.0, 8.0, 16.0, 8.0, 16.0],
+                [0.0, 0.0, 0.0, 8.0, 8.0, 8.0],
+            ])
+        );
+        assert_eq!(
+            shape("minecraft:oak_stairs[facing=east,half=top,shape=outer_right]"),
+            pixels(&[TOP, [8.0, 0.0, 8.0, 16.0, 8.0, 16.0]])
+        );
+    }
+
+    #[test]
+    fn open_doors_swing_towards_their_hinge() {
+        let door = |facing: &str, hinge: &str, open: bool| {
+            shape(&format!(
+                "minecraft:oak_door[facing={facing},half=upper,hinge={hinge},open={open}]"
+            ))
+        };
+        let north = [0.0, 0.0, 13.0, 16.0, 16.0, 16.0];
+        let south = [0.0, 0.0, 0.0, 16.0, 16.0, 3.0];
+        let west = [13.0, 0.0, 0.0, 16.0, 16.0, 16.0];
+        let east = [0.0, 0.0, 0.0, 3.0, 16.0, 16.0];
+
+        for (facing, closed, right, left) in [
+            ("north", north, west, east),
+            ("south", south, east, west),
+            ("west", west, south, north),
+            ("east", east, north, south),
+        ] {
+            assert_eq!(door(facing, "left", false), pixels(&[closed]), "{facing}");
+            assert_eq!(door(facing, "right", false), pixels(&[closed]), "{facing}");
+            assert_eq!(door(facing, "right", true), pixels(&[right]), "{facing}");
+            assert_eq!(door(facing, "left", true), pixels(&[left]), "{facing}");
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::schematic::nbt::NbtTag;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BlockState {
+    pub name: String,
+    pub properties: BTreeMap<String, String>,
+}
+
+impl BlockState {
+    /// Palette entry in the `{Name, Properties}` compound form used by Litematica and
+    /// structure files.
+    pub fn from_nbt(tag: &NbtTag) -> Option<Self> {
+        let name = tag.get("Name")?.as_str()?.to_string();
+        let properties = tag
+            .get("Properties")
+            .and_then(NbtTag::as_compound)
+            .map(|props| {
+                props
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self { name, properties })
+    }
+
+    /// Palette key in the `minecraft:oak_stairs[facing=east,half=bottom]` form used by
+    /// Sponge schematics.
+    pub fn parse(state: &str) -> Self {
+        let (name, properties) = match state.split_once('[') {
+            Some((name, rest)) => {
+                let properties = rest
+                    .trim_end_matches(']')
+                    .split(',')
+                    .filter_map(|pair| pair.split_once('='))
+                    .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                    .collect();
+                (name, properties)
+            }
+            None => (state, BTreeMap::new()),
+        };
+
+        Self {
+            name: name.trim().to_string(),
+            properties,
+        }
+    }
+
+    /// Block id without the `minecraft:` namespace.
+    pub fn id(&self) -> &str {
+        self.name.strip_prefix("minecraft:").unwrap_or(&self.name)
+    }
+
+    pub fn property(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).map(String::as_str)
+    }
+
+    /// Air, and the structure void marker that leaves the world untouched when placed.
+    pub fn is_empty(&self) -> bool {
+        matches!(
+            self.id(),
+            "air" | "cave_air" | "void_air" | "structure_void"
+        )
+    }
+}
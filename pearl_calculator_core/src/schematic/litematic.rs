@@ -0,0 +1,188 @@
+use crate::schematic::block_state::BlockState;
+use crate::schematic::nbt::NbtTag;
+use crate::schematic::packed_array::{bits_for_palette, read_spanning};
+use crate::schematic::schematic::{BlockPos, Schematic, SchematicBlock};
+use std::error::Error;
+
+/// Reads every region of a Litematica `.litematic` file into schematic coordinates.
+pub fn read_litematic(root: &NbtTag) -> Result<Schematic, Box<dyn Error>> {
+    let regions = root
+        .get("Regions")
+        .and_then(NbtTag::as_compound)
+        .ok_or("Litematic is missing its Regions")?;
+
+    let mut schematic = Schematic::default();
+    let mut region_names: Vec<&String> = regions.keys().collect();
+    region_names.sort();
+
+    for name in region_names {
+        read_region(&regions[name], &mut schematic)
+            .map_err(|e| format!("Litematic region '{name}': {e}"))?;
+    }
+
+    Ok(schematic)
+}
+
+fn read_region(region: &NbtTag, schematic: &mut Schematic) -> Result<(), Box<dyn Error>> {
+    let position = read_vec3(region.get("Position").ok_or("missing Position")?)?;
+    let size = read_vec3(region.get("Size").ok_or("missing Size")?)?;
+
+    // Negative sizes extend the region backwards from its anchor block.
+    let corner = |pos: i32, size: i32| if size < 0 { pos + size + 1 } else { pos };
+    let min = BlockPos::new(
+        corner(position.x, size.x),
+        corner(position.y, size.y),
+        corner(position.z, size.z),
+    );
+    let (sx, sy, sz) = (
+        size.x.unsigned_abs() as usize,
+        size.y.unsigned_abs() as usize,
+        size.z.unsigned_abs() as usize,
+    );
+
+    let palette: Vec<BlockState> = region
+        .get("BlockStatePalette")
+        .and_then(NbtTag::as_list)
+        .ok_or("missing BlockStatePalette")?
+        .iter()
+        .map(|entry| BlockState::from_nbt(entry).ok_or("invalid palette entry"))
+        .collect::<Result<_, _>>()?;
+    if palette.is_empty() {
+        return Ok(());
+    }
+
+    let states = match region.get("BlockStates") {
+        Some(NbtTag::LongArray(longs)) => longs,
+        _ => return Err("missing BlockStates".into()),
+    };
+
+    // Sizes come straight from the file, so a corrupt one must not wrap around.
+    let bits = bits_for_palette(palette.len(), 2);
+    let volume = sx
+        .checked_mul(sy)
+        .and_then(|area| area.checked_mul(sz))
+        .ok_or("region is too large")?;
+    let needed_bits = volume.checked_mul(bits).ok_or("region is too large")?;
+    if states.len().saturating_mul(64) < needed_bits {
+        return Err("BlockStates is shorter than the region volume".into());
+    }
+
+    let palette_offset = schematic.palette.len();
+    schematic.palette.extend(palette.iter().cloned());
+
+    for index in 0..volume {
+        let state = read_spanning(states, index, bits).ok_or("BlockStates ended early")?;
+        let block = palette
+            .get(state)
+            .ok_or("block state outside the palette")?;
+        if block.is_empty() {
+            continue;
+        }
+
+        let x = index % sx;
+        let z = (index / sx) % sz;
+        let y = index / (sx * sz);
+        schematic.blocks.push(SchematicBlock {
+            pos: BlockPos::new(min.x + x as i32, min.y + y as i32, min.z + z as i32),
+            state: palette_offset + state,
+        });
+    }
+
+    Ok(())
+}
+
+fn read_vec3(tag: &NbtTag) -> Result<BlockPos, Box<dyn Error>> {
+    let axis = |key: &str| {
+        tag.get(key)
+            .and_then(NbtTag::as_i64)
+            .map(|v| v as i32)
+            .ok_or_else(|| format!("missing {key} coordinate"))
+    };
+    Ok(BlockPos::new(axis("x")?, axis("y")?, axis("z")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schematic::packed_array::tests::pack_spanning;
+    use std::collections::HashMap;
+
+    fn compound(entries: Vec<(&str, NbtTag)>) -> NbtTag {
+        NbtTag::Compound(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    fn vec3(x: i32, y: i32, z: i32) -> NbtTag {
+        compound(vec![
+            ("x", NbtTag::Int(x)),
+            ("y", NbtTag::Int(y)),
+            ("z", NbtTag::Int(z)),
+        ])
+    }
+
+    fn region(size: (i32, i32, i32), palette: &[&str], states: Vec<i64>) -> NbtTag {
+        let palette = palette
+            .iter()
+            .map(|name| compound(vec![("Name", NbtTag::String(name.to_string()))]))
+            .collect();
+        let region = compound(vec![
+            ("Position", vec3(5, 64, -3)),
+            ("Size", vec3(size.0, size.1, size.2)),
+            ("BlockStatePalette", NbtTag::List(palette)),
+            ("BlockStates", NbtTag::LongArray(states)),
+        ]);
+        compound(vec![("Regions", compound(vec![("main", region)]))])
+    }
+
+    #[test]
+    fn reads_spanning_states_of_a_backwards_region() {
+        let palette = [
+            "minecraft:air",
+            "minecraft:stone",
+            "minecraft:glass",
+            "minecraft:dirt",
+            "minecraft:sand",
+        ];
+        // Five states take three bits, so entry 21 straddles the first two longs.
+        let (sx, sy, sz) = (11, 2, 2);
+        let states: Vec<usize> = (0..sx * sy * sz).map(|index| index % 5).collect();
+        let root = region(
+            (-(sx as i32), sy as i32, sz as i32),
+            &palette,
+            pack_spanning(&states, 3),
+        );
+
+        let schematic = read_litematic(&root).unwrap();
+        let expected: Vec<(BlockPos, &str)> = states
+            .iter()
+            .enumerate()
+            .filter(|&(_, &state)| state != 0)
+            .map(|(index, &state)| {
+                let (x, z, y) = (index % sx, (index / sx) % sz, index / (sx * sz));
+                // The region reaches back from x 5 to x -5.
+                let pos = BlockPos::new(-5 + x as i32, 64 + y as i32, -3 + z as i32);
+                (pos, palette[state])
+            })
+            .collect();
+        let blocks: Vec<(BlockPos, &str)> = schematic
+            .blocks
+            .iter()
+            .map(|block| (block.pos, schematic.palette[block.state].name.as_str()))
+            .collect();
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn rejects_short_or_oversized_regions() {
+        let palette = ["minecraft:air", "minecraft:stone"];
+        let short = region((4, 4, 4), &palette, vec![0; 1]);
+        assert!(read_litematic(&short).is_err());
+
+        let huge = region((i32::MAX, i32::MAX, i32::MAX), &palette, vec![0; 1]);
+        assert!(read_litematic(&huge).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+use flate2::read::{GzDecoder, ZlibDecoder};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::Read;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NbtTag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<i8>),
+    String(String),
+    List(Vec<NbtTag>),
+    Compound(HashMap<String, NbtTag>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+impl NbtTag {
+    pub fn get(&self, key: &str) -> Option<&NbtTag> {
+        match self {
+            NbtTag::Compound(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    /// Any integral tag widened to `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            NbtTag::Byte(v) => Some(*v as i64),
+            NbtTag::Short(v) => Some(*v as i64),
+            NbtTag::Int(v) => Some(*v as i64),
+            NbtTag::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            NbtTag::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[NbtTag]> {
+        match self {
+            NbtTag::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_compound(&self) -> Option<&HashMap<String, NbtTag>> {
+        match self {
+            NbtTag::Compound(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Int arrays and lists of ints, which different writers use interchangeably.
+    pub fn as_int_vec(&self) -> Option<Vec<i32>> {
+        match self {
+            NbtTag::IntArray(v) => Some(v.clone()),
+            NbtTag::List(v) => v.iter().map(|t| t.as_i64().map(|i| i as i32)).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a named root tag, inflating gzip or zlib input first when it carries the magic.
+pub fn read_nbt(bytes: &[u8]) -> Result<(String, NbtTag), Box<dyn Error>> {
+    let data = decompress(bytes)?;
+    let mut reader = NbtReader {
+        data: &data,
+        pos: 0,
+    };
+
+    let tag_type = reader.read_u8()?;
+    if tag_type != TAG_COMPOUND {
+        return Err(format!("NBT root must be a compound, found tag type {tag_type}").into());
+    }
+    let name = reader.read_string()?;
+    let root = reader.read_payload(tag_type, 0)?;
+    Ok((name, root))
+}
+
+/// Inflates gzip or zlib input and passes anything else through. Output is capped at
+/// [`MAX_DECOMPRESSED_LEN`], so a small crafted file cannot exhaust memory.
+pub fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    match bytes {
+        [0x1f, 0x8b, ..] => inflate(GzDecoder::new(bytes), MAX_DECOMPRESSED_LEN),
+        [0x78, second, ..] if (0x7800u16 | *second as u16).is_multiple_of(31) => {
+            inflate(ZlibDecoder::new(bytes), MAX_DECOMPRESSED_LEN)
+        }
+        _ => Ok(bytes.to_vec()),
+    }
+}
+
+fn inflate(decoder: impl Read, limit: usize) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut out = Vec::new();
+    decoder.take(limit as u64 + 1).read_to_end(&mut out)?;
+    if out.len() > limit {
+        return Err(format!("Decompressed data is larger than {limit} bytes").into());
+    }
+    Ok(out)
+}
+
+/// Far more than any schematic or chunk decompresses to; larger output comes from a crafted
+/// or corrupt file.
+pub const MAX_DECOMPRESSED_LEN: usize = 1 << 29;
+
+const TAG_END: u8 = 0;
+const TAG_COMPOUND: u8 = 10;
+const MAX_DEPTH: usize = 512;
+
+struct NbtReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NbtReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Box<dyn Error>> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or("Unexpected end of NBT data")?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Box<dyn Error>> {
+        Ok(self.take(N)?.try_into()?)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Box<dyn Error>> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_i32(&mut self) -> Result<i32, Box<dyn Error>> {
+        Ok(i32::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_len(&mut self) -> Result<usize, Box<dyn Error>> {
+        let len = self.read_i32()?;
+        // Every element takes at least one byte, so longer lengths are corrupt.
+        if len < 0 || len as usize > self.data.len() - self.pos {
+            return Err(format!("Invalid NBT length {len}").into());
+        }
+        Ok(len as usize)
+    }
+
+    fn read_string(&mut self) -> Result<String, Box<dyn Error>> {
+        let len = u16::from_be_bytes(self.read_array()?) as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    fn read_payload(&mut self, tag_type: u8, depth: usize) -> Result<NbtTag, Box<dyn Error>> {
+        if depth > MAX_DEPTH {
+            return Err("NBT nesting too deep".into());
+        }
+
+        Ok(match tag_type {
+            1 => NbtTag::Byte(self.read_u8()? as i8),
+            2 => NbtTag::Short(i16::from_be_bytes(self.read_array()?)),
+            3 => NbtTag::Int(self.read_i32()?),
+            4 => NbtTag::Long(i64::from_be_bytes(self.read_array()?)),
+            5 => NbtTag::Float(f32::from_be_bytes(self.read_array()?)),
+            6 => NbtTag::Double(f64::from_be_bytes(self.read_array()?)),
+            7 => {
+                let len = self.read_len()?;
+                NbtTag::ByteArray(self.take(len)?.iter().map(|&b| b as i8).collect())
+            }
+            8 => NbtTag::String(self.read_string()?),
+            9 => {
+                let element_type = self.read_u8()?;
+                let len = self.read_len()?;
+                if element_type == TAG_END && len > 0 {
+                    return Err("NBT list of end tags".into());
+                }
+                let mut items = Vec::with_capacity(len);
+                for _ in 0..len {
+                    items.push(self.read_payload(element_type, depth + 1)?);
+                }
+                NbtTag::List(items)
+            }
+            TAG_COMPOUND => {
+                let mut map = HashMap::new();
+                loop {
+                    let child_type = self.read_u8()?;
+                    if child_type == TAG_END {
+                        break;
+                    }
+                    let name = self.read_string()?;
+                    map.insert(name, self.read_payload(child_type, depth + 1)?);
+                }
+                NbtTag::Compound(map)
+            }
+            11 => {
+                let len = self.read_len()?;
+                let mut items = Vec::with_capacity(len);
+                for _ in 0..len {
+                    items.push(self.read_i32()?);
+                }
+                NbtTag::IntArray(items)
+            }
+            12 => {
+                let len = self.read_len()?;
+                let mut items = Vec::with_capacity(len);
+                for _ in 0..len {
+                    items.push(i64::from_be_bytes(self.read_array()?));
+                }
+                NbtTag::LongArray(items)
+            }
+            other => return Err(format!("Unknown NBT tag type {other}").into()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use std::io::Write;
+
+    fn named(tag_type: u8, name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![tag_type];
+        bytes.extend((name.len() as u16).to_be_bytes());
+        bytes.extend(name.as_bytes());
+        bytes.extend(payload);
+        bytes
+    }
+
+    fn sample() -> Vec<u8> {
+        let mut list = vec![3];
+        list.extend(2i32.to_be_bytes());
+        list.extend(7i32.to_be_bytes());
+        list.extend((-7i32).to_be_bytes());
+
+        let mut longs = 2i32.to_be_bytes().to_vec();
+        longs.extend(i64::MIN.to_be_bytes());
+        longs.extend(1i64.to_be_bytes());
+
+        let mut inner = named(8, "Name", &[0, 5, b's', b't', b'o', b'n', b'e']);
+        inner.push(TAG_END);
+
+        let mut root = Vec::new();
+        root.extend(named(1, "byte", &[0xff]));
+        root.extend(named(2, "short", &300i16.to_be_bytes()));
+        root.extend(named(6, "double", &0.5f64.to_be_bytes()));
+        root.extend(named(9, "list", &list));
+        root.extend(named(12, "longs", &longs));
+        root.extend(named(TAG_COMPOUND, "inner", &inner));
+        root.push(TAG_END);
+        named(TAG_COMPOUND, "root", &root)
+    }
+
+    fn check_sample(bytes: &[u8]) {
+        let (name, root) = read_nbt(bytes).unwrap();
+        assert_eq!(name, "root");
+        assert_eq!(root.get("byte"), Some(&NbtTag::Byte(-1)));
+        assert_eq!(root.get("short").and_then(NbtTag::as_i64), Some(300));
+        assert_eq!(root.get("double"), Some(&NbtTag::Double(0.5)));
+        assert_eq!(
+            root.get("list").and_then(NbtTag::as_int_vec),
+            Some(vec![7, -7])
+        );
+        assert_eq!(
+            root.get("longs"),
+            Some(&NbtTag::LongArray(vec![i64::MIN, 1]))
+        );
+        assert_eq!(
+            root.get("inner")
+                .and_then(|inner| inner.get("Name"))
+                .and_then(NbtTag::as_str),
+            Some("stone")
+        );
+    }
+
+    #[test]
+    fn reads_plain_gzip_and_zlib() {
+        let plain = sample();
+        check_sample(&plain);
+
+        let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+        gzip.write_all(&plain).unwrap();
+        check_sample(&gzip.finish().unwrap());
+
+        let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+        zlib.write_all(&plain).unwrap();
+        check_sample(&zlib.finish().unwrap());
+    }
+
+    #[test]
+    fn rejects_truncated_and_corrupt_input() {
+        let plain = sample();
+        for len in 0..plain.len() {
+            assert!(read_nbt(&plain[..len]).is_err(), "prefix of {len} bytes");
+        }
+
+        let mut huge_list = vec![3];
+        huge_list.extend(i32::MAX.to_be_bytes());
+        let mut root = named(9, "list", &huge_list);
+        root.push(TAG_END);
+        assert!(read_nbt(&named(TAG_COMPOUND, "", &root)).is_err());
+
+        let mut root = named(7, "bytes", &(-1i32).to_be_bytes());
+        root.push(TAG_END);
+        assert!(read_nbt(&named(TAG_COMPOUND, "", &root)).is_err());
+
+        let mut deep = Vec::new();
+        for _ in 0..=MAX_DEPTH + 1 {
+            deep.extend([TAG_COMPOUND, 0, 0]);
+        }
+        deep.extend(vec![TAG_END; MAX_DEPTH + 3]);
+        assert!(read_nbt(&deep).is_err());
+    }
+
+    #[test]
+    fn caps_decompressed_size() {
+        let mut gzip = GzEncoder::new(Vec::new(), Compression::best());
+        gzip.write_all(&[0; 4096]).unwrap();
+        let compressed = gzip.finish().unwrap();
+
+        assert_eq!(
+            inflate(GzDecoder::new(&compressed[..]), 4096)
+                .unwrap()
+                .len(),
+            4096
+        );
+        assert!(inflate(GzDecoder::new(&compressed[..]), 4095).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+/// Entry `index` of a long array packed back to back, where entries may straddle two
+/// longs. Used by Litematica and by chunks before 1.16.
+pub fn read_spanning(longs: &[i64], index: usize, bits: usize) -> Option<usize> {
+    let mask = (1u64 << bits) - 1;
+    let start_bit = index * bits;
+    let start_long = start_bit >> 6;
+    let end_long = (start_bit + bits - 1) >> 6;
+    let shift = start_bit & 63;
+
+    let low = *longs.get(start_long)? as u64 >> shift;
+    let value = if start_long == end_long {
+        low
+    } else {
+        low | ((*longs.get(end_long)? as u64) << (64 - shift))
+    };
+    Some((value & mask) as usize)
+}
+
+/// Entry `index` of a long array where each long holds `64 / bits` whole entries and
+/// the leftover high bits are padding, as chunks store block states since 1.16.
+pub fn read_aligned(longs: &[i64], index: usize, bits: usize) -> Option<usize> {
+    let mask = (1u64 << bits) - 1;
+    let per_long = 64 / bits;
+    let long = *longs.get(index / per_long)? as u64;
+    Some(((long >> ((index % per_long) * bits)) & mask) as usize)
+}
+
+/// Bits per entry for a palette of `len` states, never below `min_bits`.
+pub fn bits_for_palette(len: usize, min_bits: usize) -> usize {
+    ((usize::BITS - len.saturating_sub(1).leading_zeros()) as usize).max(min_bits)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Packs `values` back to back, letting entries straddle two longs.
+    pub(crate) fn pack_spanning(values: &[usize], bits: usize) -> Vec<i64> {
+        let mut longs = vec![0u64; (values.len() * bits).div_ceil(64)];
+        for (index, &value) in values.iter().enumerate() {
+            let start_bit = index * bits;
+            let shift = start_bit & 63;
+            longs[start_bit >> 6] |= (value as u64) << shift;
+            if shift + bits > 64 {
+                longs[(start_bit >> 6) + 1] |= (value as u64) >> (64 - shift);
+            }
+        }
+        longs.into_iter().map(|long| long as i64).collect()
+    }
+
+    fn pack_aligned(values: &[usize], bits: usize) -> Vec<i64> {
+        let per_long = 64 / bits;
+        values
+            .chunks(per_long)
+            .map(|chunk| {
+                chunk.iter().enumerate().fold(0u64, |long, (i, &value)| {
+                    long | (value as u64) << (i * bits)
+                }) as i64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn spanning_and_aligned_layouts_differ_past_the_first_long() {
+        let bits = 5;
+        let values: Vec<usize> = (0..40).map(|i| (i * 7 + 3) % 32).collect();
+        let spanning = pack_spanning(&values, bits);
+        let aligned = pack_aligned(&values, bits);
+        assert_eq!(spanning.len(), 4);
+        assert_eq!(aligned.len(), 4);
+
+        for (index, &value) in values.iter().enumerate() {
+            assert_eq!(read_spanning(&spanning, index, bits), Some(value));
+            assert_eq!(read_aligned(&aligned, index, bits), Some(value));
+        }
+        // Entry 12 straddles the first two longs when spanning, and starts the second long
+        // when aligned, after four bits of padding.
+        assert_eq!(
+            read_spanning(&aligned, 12, bits),
+            Some((values[12] << 4) & 31)
+        );
+        assert_eq!(read_spanning(&spanning, 52, bits), None);
+        assert_eq!(read_aligned(&aligned, 48, bits), None);
+    }
+
+    #[test]
+    fn palette_bits_round_up_with_a_floor() {
+        assert_eq!(bits_for_palette(0, 2), 2);
+        assert_eq!(bits_for_palette(1, 4), 4);
+        assert_eq!(bits_for_palette(16, 4), 4);
+        assert_eq!(bits_for_palette(17, 4), 5);
+        assert_eq!(bits_for_palette(5, 2), 3);
+    }
+}
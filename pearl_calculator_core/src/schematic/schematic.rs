@@ -0,0 +1,176 @@
+use crate::physics::aabb::aabb_box::AABBBox;
+use crate::physics::entities::movement::PearlVersion;
+use crate::physics::world::collision_world::CollisionWorld;
+use crate::physics::world::fluid::{FluidKind, FluidRegion};
+use crate::physics::world::space::Space3D;
+use crate::schematic::block_shapes::collision_shape;
+use crate::schematic::block_state::BlockState;
+use crate::schematic::litematic::read_litematic;
+use crate::schematic::nbt::{NbtTag, read_nbt};
+use crate::schematic::sponge::read_sponge;
+use crate::schematic::structure::read_structure;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BlockPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl BlockPos {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchematicFormat {
+    Litematic,
+    Sponge,
+    Structure,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchematicBlock {
+    pub pos: BlockPos,
+    pub state: usize,
+}
+
+/// Non-air blocks of a build, relative to the schematic origin.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Schematic {
+    pub palette: Vec<BlockState>,
+    pub blocks: Vec<SchematicBlock>,
+}
+
+impl Schematic {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses a gzipped or raw NBT schematic, picking the format from its root tags.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let (_, root) = read_nbt(bytes)?;
+        let format = detect_format(&root).ok_or("Unrecognised schematic format")?;
+        Self::from_nbt(&root, format)
+    }
+
+    pub fn from_nbt(root: &NbtTag, format: SchematicFormat) -> Result<Self, Box<dyn Error>> {
+        match format {
+            SchematicFormat::Litematic => read_litematic(root),
+            SchematicFormat::Sponge => read_sponge(root),
+            SchematicFormat::Structure => read_structure(root),
+        }
+    }
+
+    pub fn state_of(&self, block: &SchematicBlock) -> &BlockState {
+        &self.palette[block.state]
+    }
+
+    /// Collision boxes with the schematic origin placed at `origin`. The origin must be in
+    /// the simulation's frame, i.e. world coordinates minus the cannon offset.
+    pub fn collision_boxes(&self, origin: Space3D, version: PearlVersion) -> Vec<AABBBox> {
+        let shapes: Vec<Vec<AABBBox>> = self
+            .palette
+            .iter()
+            .map(|state| collision_shape(state, version))
+            .collect();
+
+        self.blocks
+            .iter()
+            .flat_map(|block| {
+                let x = origin.x + block.pos.x as f64;
+                let y = origin.y + block.pos.y as f64;
+                let z = origin.z + block.pos.z as f64;
+                shapes[block.state].iter().map(move |shape| {
+                    AABBBox::new(
+                        x + shape.min_x,
+                        y + shape.min_y,
+                        z + shape.min_z,
+                        x + shape.max_x,
+                        y + shape.max_y,
+                        z + shape.max_z,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Water and lava volumes, including waterlogged blocks, placed like
+    /// [`Schematic::collision_boxes`]. A fluid reaches the top of its block only when the
+    /// same fluid is above it.
+    pub fn fluid_regions(&self, origin: Space3D) -> Vec<FluidRegion> {
+        let fluids: Vec<Option<(FluidKind, f64)>> = self.palette.iter().map(fluid_of).collect();
+        let by_position: HashMap<BlockPos, FluidKind> = self
+            .blocks
+            .iter()
+            .filter_map(|block| fluids[block.state].map(|(kind, _)| (block.pos, kind)))
+            .collect();
+
+        self.blocks
+            .iter()
+            .filter_map(|block| {
+                let (kind, height) = fluids[block.state]?;
+                let above = BlockPos::new(block.pos.x, block.pos.y + 1, block.pos.z);
+                let height = if by_position.get(&above) == Some(&kind) {
+                    1.0
+                } else {
+                    height
+                };
+
+                let x = origin.x + block.pos.x as f64;
+                let y = origin.y + block.pos.y as f64;
+                let z = origin.z + block.pos.z as f64;
+                Some(FluidRegion::new(
+                    AABBBox::new(x, y, z, x + 1.0, y + height, z + 1.0),
+                    kind,
+                ))
+            })
+            .collect()
+    }
+
+    pub fn to_collision_world(&self, origin: Space3D, version: PearlVersion) -> CollisionWorld {
+        CollisionWorld::new(self.collision_boxes(origin, version))
+            .with_fluids(self.fluid_regions(origin))
+    }
+}
+
+/// Fluid in a block and its surface height when nothing is above it: 8/9 for sources
+/// and falling fluid, less for each level of flowing fluid.
+fn fluid_of(state: &BlockState) -> Option<(FluidKind, f64)> {
+    let kind = match state.id() {
+        "lava" => FluidKind::Lava,
+        "water" | "bubble_column" | "kelp" | "kelp_plant" | "seagrass" | "tall_seagrass" => {
+            FluidKind::Water
+        }
+        _ if state.property("waterlogged") == Some("true") => FluidKind::Water,
+        _ => return None,
+    };
+
+    let level: u32 = state
+        .property("level")
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(0);
+    let amount = if level == 0 || level >= 8 {
+        8
+    } else {
+        8 - level
+    };
+    Some((kind, amount as f64 / 9.0))
+}
+
+pub fn detect_format(root: &NbtTag) -> Option<SchematicFormat> {
+    if root.get("Regions").is_some() {
+        Some(SchematicFormat::Litematic)
+    } else if root.get("Schematic").is_some() || root.get("BlockData").is_some() {
+        Some(SchematicFormat::Sponge)
+    } else if root.get("blocks").is_some() && root.get("size").is_some() {
+        Some(SchematicFormat::Structure)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,199 @@
+use crate::schematic::block_state::BlockState;
+use crate::schematic::nbt::NbtTag;
+use crate::schematic::schematic::{BlockPos, Schematic, SchematicBlock};
+use std::error::Error;
+
+/// Reads a Sponge `.schem` file, version 2 (flat root) or 3 (nested `Schematic` and
+/// `Blocks` compounds). Blocks are shifted by the stored `Offset`, as WorldEdit pastes them.
+pub fn read_sponge(root: &NbtTag) -> Result<Schematic, Box<dyn Error>> {
+    let schem = root.get("Schematic").unwrap_or(root);
+
+    // Dimensions are unsigned shorts.
+    let dimension = |key: &str| {
+        schem
+            .get(key)
+            .and_then(NbtTag::as_i64)
+            .map(|v| (v & 0xFFFF) as usize)
+            .ok_or_else(|| format!("Sponge schematic is missing {key}"))
+    };
+    let (width, height, length) = (
+        dimension("Width")?,
+        dimension("Height")?,
+        dimension("Length")?,
+    );
+
+    let offset = match schem.get("Offset").and_then(NbtTag::as_int_vec) {
+        Some(offset) if offset.len() == 3 => BlockPos::new(offset[0], offset[1], offset[2]),
+        _ => BlockPos::default(),
+    };
+
+    let (palette_tag, data_tag) = match schem.get("Blocks") {
+        Some(blocks) => (blocks.get("Palette"), blocks.get("Data")),
+        None => (schem.get("Palette"), schem.get("BlockData")),
+    };
+
+    let palette_map = palette_tag
+        .and_then(NbtTag::as_compound)
+        .ok_or("Sponge schematic is missing its block palette")?;
+    let entries = palette_map
+        .iter()
+        .map(|(key, id)| match id.as_i64() {
+            Some(id) if (0..=MAX_PALETTE_ID).contains(&id) => Ok((id as usize, key)),
+            _ => Err("Sponge palette id is out of range"),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Ids the palette skips read as air rather than as a nameless block.
+    let palette_len = entries.iter().map(|(id, _)| id + 1).max().unwrap_or(0);
+    let mut palette = vec![BlockState::parse("minecraft:air"); palette_len];
+    for (id, key) in entries {
+        palette[id] = BlockState::parse(key);
+    }
+
+    let data = match data_tag {
+        Some(NbtTag::ByteArray(data)) => data,
+        _ => return Err("Sponge schematic is missing its block data".into()),
+    };
+
+    // Every block takes at least one byte, which also bounds the loop below.
+    let volume = width
+        .checked_mul(height)
+        .and_then(|area| area.checked_mul(length))
+        .ok_or("Sponge schematic is too large")?;
+    if data.len() < volume {
+        return Err("Sponge block data is shorter than the schematic volume".into());
+    }
+
+    let mut schematic = Schematic {
+        palette,
+        blocks: Vec::new(),
+    };
+
+    let mut bytes = data.iter().map(|&b| b as u8);
+    for index in 0..volume {
+        let state = read_varint(&mut bytes).ok_or("Sponge block data ended early")?;
+        let block = schematic
+            .palette
+            .get(state)
+            .ok_or("block state outside the palette")?;
+        if block.is_empty() {
+            continue;
+        }
+
+        let x = index % width;
+        let z = (index / width) % length;
+        let y = index / (width * length);
+        schematic.blocks.push(SchematicBlock {
+            pos: BlockPos::new(
+                offset.x + x as i32,
+                offset.y + y as i32,
+                offset.z + z as i32,
+            ),
+            state,
+        });
+    }
+
+    Ok(schematic)
+}
+
+const MAX_PALETTE_ID: i64 = 1 << 20;
+
+fn read_varint(bytes: &mut impl Iterator<Item = u8>) -> Option<usize> {
+    let mut value = 0usize;
+    for shift in (0..35).step_by(7) {
+        let byte = bytes.next()?;
+        value |= ((byte & 0x7F) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn compound(entries: Vec<(&str, NbtTag)>) -> NbtTag {
+        NbtTag::Compound(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    fn version_3(palette: Vec<(&str, i32)>, data: Vec<u8>) -> NbtTag {
+        let palette = palette
+            .into_iter()
+            .map(|(key, id)| (key, NbtTag::Int(id)))
+            .collect();
+        let blocks = compound(vec![
+            ("Palette", compound(palette)),
+            (
+                "Data",
+                NbtTag::ByteArray(data.into_iter().map(|b| b as i8).collect()),
+            ),
+        ]);
+        let schematic = compound(vec![
+            ("Width", NbtTag::Short(2)),
+            ("Height", NbtTag::Short(1)),
+            ("Length", NbtTag::Short(2)),
+            ("Offset", NbtTag::IntArray(vec![10, -64, 3])),
+            ("Blocks", blocks),
+        ]);
+        compound(vec![("Schematic", schematic)])
+    }
+
+    #[test]
+    fn reads_varints() {
+        let read = |bytes: &[u8]| read_varint(&mut bytes.iter().copied());
+        assert_eq!(read(&[0x00]), Some(0));
+        assert_eq!(read(&[0x7F]), Some(127));
+        assert_eq!(read(&[0x80, 0x01]), Some(128));
+        assert_eq!(read(&[0x96, 0x01, 0x05]), Some(150));
+        assert_eq!(
+            read(&[0xFF, 0xFF, 0xFF, 0xFF, 0x07]),
+            Some(i32::MAX as usize)
+        );
+        assert_eq!(read(&[0x80]), None);
+        assert_eq!(read(&[0x80; 5]), None);
+    }
+
+    #[test]
+    fn reads_multi_byte_ids_and_palette_gaps_as_air() {
+        // Id 1 is missing from the palette; id 200 needs two varint bytes.
+        let root = version_3(
+            vec![("minecraft:stone", 0), ("minecraft:glass", 200)],
+            vec![0x00, 0x01, 0xC8, 0x01, 0x00],
+        );
+        let schematic = read_sponge(&root).unwrap();
+        assert_eq!(schematic.palette[1].name, "minecraft:air");
+
+        let blocks: Vec<(BlockPos, &str)> = schematic
+            .blocks
+            .iter()
+            .map(|block| (block.pos, schematic.palette[block.state].id()))
+            .collect();
+        assert_eq!(
+            blocks,
+            [
+                (BlockPos::new(10, -64, 3), "stone"),
+                (BlockPos::new(10, -64, 4), "glass"),
+                (BlockPos::new(11, -64, 4), "stone"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_short_data_and_bad_ids() {
+        let short = version_3(vec![("minecraft:stone", 0)], vec![0x00, 0x00, 0x00]);
+        assert!(read_sponge(&short).is_err());
+
+        let outside = version_3(vec![("minecraft:stone", 0)], vec![0x00, 0x00, 0x00, 0x05]);
+        assert!(read_sponge(&outside).is_err());
+
+        let negative = version_3(vec![("minecraft:stone", -1)], vec![0x00; 4]);
+        assert!(read_sponge(&negative).is_err());
+    }
+}
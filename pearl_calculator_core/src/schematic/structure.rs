@@ -0,0 +1,61 @@
+use crate::schematic::block_state::BlockState;
+use crate::schematic::nbt::NbtTag;
+use crate::schematic::schematic::{BlockPos, Schematic, SchematicBlock};
+use std::error::Error;
+
+/// Reads a vanilla structure block `.nbt` file. Files with several `palettes` (shipwrecks
+/// and the like) use the first one.
+pub fn read_structure(root: &NbtTag) -> Result<Schematic, Box<dyn Error>> {
+    let palette_list = root
+        .get("palette")
+        .and_then(NbtTag::as_list)
+        .or_else(|| {
+            root.get("palettes")
+                .and_then(NbtTag::as_list)
+                .and_then(|palettes| palettes.first())
+                .and_then(NbtTag::as_list)
+        })
+        .ok_or("Structure is missing its palette")?;
+
+    let palette: Vec<BlockState> = palette_list
+        .iter()
+        .map(|entry| BlockState::from_nbt(entry).ok_or("invalid palette entry"))
+        .collect::<Result<_, _>>()?;
+
+    let blocks = root
+        .get("blocks")
+        .and_then(NbtTag::as_list)
+        .ok_or("Structure is missing its blocks")?;
+
+    let mut schematic = Schematic {
+        palette,
+        blocks: Vec::with_capacity(blocks.len()),
+    };
+
+    for block in blocks {
+        let state = block
+            .get("state")
+            .and_then(NbtTag::as_i64)
+            .ok_or("structure block without a state")? as usize;
+        let pos = block
+            .get("pos")
+            .and_then(NbtTag::as_int_vec)
+            .filter(|pos| pos.len() == 3)
+            .ok_or("structure block without a position")?;
+
+        let block_state = schematic
+            .palette
+            .get(state)
+            .ok_or("block state outside the palette")?;
+        if block_state.is_empty() {
+            continue;
+        }
+
+        schematic.blocks.push(SchematicBlock {
+            pos: BlockPos::new(pos[0], pos[1], pos[2]),
+            state,
+        });
+    }
+
+    Ok(schematic)
+}
@@ -1,5 +1,6 @@
 use super::types::*;
 use crate::calculation::inputs::GeneralData;
+use crate::physics::world::dimension::Dimension;
 use crate::physics::world::space::Space3D;
 
 impl Default for AppSettings {
@@ -24,11 +25,16 @@ impl Default for CannonSettings {
             north_east_tnt: Space3D::default(),
             south_west_tnt: Space3D::default(),
             south_east_tnt: Space3D::default(),
+            north_tnt: Space3D::default(),
+            south_tnt: Space3D::default(),
+            west_tnt: Space3D::default(),
+            east_tnt: Space3D::default(),
             offset: Surface2D { x: 0.0, z: 0.0 },
             pearl: PearlInfo {
                 motion: Space3D::default(),
                 position: Space3D::default(),
             },
+            dimension: Dimension::default(),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::physics::world::dimension::Dimension;
 use crate::physics::world::layout_direction::LayoutDirection;
 use crate::physics::world::space::Space3D;
 use serde::{Deserialize, Serialize};
@@ -45,8 +46,18 @@ pub struct CannonSettings {
     pub south_west_tnt: Space3D,
     #[serde(rename = "SouthEastTNT")]
     pub south_east_tnt: Space3D,
+    #[serde(rename = "NorthTNT", default)]
+    pub north_tnt: Space3D,
+    #[serde(rename = "SouthTNT", default)]
+    pub south_tnt: Space3D,
+    #[serde(rename = "WestTNT", default)]
+    pub west_tnt: Space3D,
+    #[serde(rename = "EastTNT", default)]
+    pub east_tnt: Space3D,
     pub offset: Surface2D,
     pub pearl: PearlInfo,
+    #[serde(default)]
+    pub dimension: Dimension,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
@@ -1,2 +1,3 @@
+pub mod java_random;
 pub mod parallel;
 pub mod utils;
@@ -0,0 +1,51 @@
+const MULTIPLIER: i64 = 0x5DEECE66D;
+const ADDEND: i64 = 0xB;
+const MASK: i64 = (1 << 48) - 1;
+const DOUBLE_UNIT: f64 = 1.0 / (1u64 << 53) as f64;
+
+/// `java.util.Random`, the 48-bit LCG vanilla's legacy random source is built on, so a seed
+/// gives the same sequence it does in Java. Gaussians can differ in the last bit, since Java
+/// takes their logarithm with `StrictMath`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JavaRandom {
+    seed: i64,
+    next_gaussian: Option<f64>,
+}
+
+impl JavaRandom {
+    pub fn new(seed: i64) -> Self {
+        Self {
+            seed: (seed ^ MULTIPLIER) & MASK,
+            next_gaussian: None,
+        }
+    }
+
+    fn next(&mut self, bits: u32) -> i32 {
+        self.seed = (self.seed.wrapping_mul(MULTIPLIER).wrapping_add(ADDEND)) & MASK;
+        (self.seed >> (48 - bits)) as i32
+    }
+
+    pub fn next_double(&mut self) -> f64 {
+        let high = (self.next(26) as i64) << 27;
+        let low = self.next(27) as i64;
+        (high + low) as f64 * DOUBLE_UNIT
+    }
+
+    /// Polar method, keeping the second value of each pair for the next call like Java does.
+    pub fn next_gaussian(&mut self) -> f64 {
+        if let Some(value) = self.next_gaussian.take() {
+            return value;
+        }
+
+        loop {
+            let v1 = 2.0 * self.next_double() - 1.0;
+            let v2 = 2.0 * self.next_double() - 1.0;
+            let s = v1 * v1 + v2 * v2;
+            if s < 1.0 && s != 0.0 {
+                let multiplier = (-2.0 * s.ln() / s).sqrt();
+                self.next_gaussian = Some(v2 * multiplier);
+                return v1 * multiplier;
+            }
+        }
+    }
+}
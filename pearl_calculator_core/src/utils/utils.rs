@@ -7,3 +7,17 @@ pub fn to_radians(degrees: f64) -> f64 {
 pub fn to_degrees(radians: f64) -> f64 {
     radians * 180.0 / PI
 }
+
+/// Minecraft's `Mth.sin`, which reads a 65536-entry table instead of computing the sine.
+pub fn mth_sin(radians: f32) -> f32 {
+    table_sin((radians * 10430.378) as i32)
+}
+
+/// Minecraft's `Mth.cos`, a quarter turn further along the same table.
+pub fn mth_cos(radians: f32) -> f32 {
+    table_sin((radians * 10430.378 + 16384.0) as i32)
+}
+
+fn table_sin(index: i32) -> f32 {
+    ((index & 0xFFFF) as f64 * PI * 2.0 / 65536.0).sin() as f32
+}
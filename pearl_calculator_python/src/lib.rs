@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
 use pearl_calculator_bridge::{
-    api, inputs::CalculationInput, inputs::PearlTraceInput, inputs::RawTraceInput,
+    api, inputs::CalculationInput, inputs::GroupCalculationInput, inputs::PearlTraceInput,
+    inputs::RawTraceInput, inputs::ChunkPathInput, inputs::ThrowInput, inputs::DispenserInput, inputs::LandingDistributionInput, inputs::SensitivityInput,
 };
 
 /// Calculate TNT amount to reach a destination
@@ -25,8 +26,10 @@ use pearl_calculator_bridge::{
 ///     ...     "defaultRedDirection": "NorthWest",
 ///     ...     "defaultBlueDirection": "NorthEast",
 ///     ...     "destinationX": 100.0, "destinationY": None, "destinationZ": 100.0,
+///     ...     "dimension": "Nether", "destinationDimension": "Overworld",
 ///     ...     "maxTnt": 100, "maxTicks": 1000, "maxDistance": 200.0,
-///     ...     "version": "Post1212"
+///     ...     "version": "Post1212",
+///     ...     "redTntOverride": None, "blueTntOverride": None
 ///     ... }
 ///     >>> result = calculate_tnt_amount(json.dumps(input_data))
 ///     >>> results = json.loads(result)
@@ -37,7 +40,49 @@ fn calculate_tnt_amount(input_json: String) -> PyResult<String> {
     })?;
 
     let results = api::calculate_tnt_amount(input).map_err(|e| {
-        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Calculation failed: {}", e))
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Chunk path calculation failed: {}", e))
+    })?;
+
+    serde_json::to_string(&results).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize result: {}", e))
+    })
+}
+
+/// Calculate TNT counts for a cannon made of named TNT groups
+///
+/// Args:
+///     input_json (str): JSON string containing group calculation input parameters
+///
+/// Returns:
+///     str: JSON string containing calculation results
+///
+/// Example:
+///     >>> import json
+///     >>> from pearl_calculator_python import calculate_group_tnt_amount
+///     >>> input_data = {
+///     ...     "pearlX": 0.0, "pearlY": 0.0, "pearlZ": 0.0,
+///     ...     "pearlMotionX": 0.0, "pearlMotionY": 0.0, "pearlMotionZ": 0.0,
+///     ...     "offsetX": 0.0, "offsetZ": 0.0, "cannonY": 0.0,
+///     ...     "groups": [
+///     ...         {"name": "coarse", "x": -1.0, "y": 0.0, "z": -1.0, "maxCount": 500},
+///     ...         {"name": "fine", "x": -3.0, "y": 0.0, "z": -3.0, "maxCount": 20},
+///     ...         {"name": "side", "x": 1.0, "y": 0.0, "z": -1.0, "maxCount": None},
+///     ...         {"name": "lift", "x": 0.0, "y": -1.0, "z": 0.0, "vertical": True}
+///     ...     ],
+///     ...     "destinationX": 100.0, "destinationY": 0.0, "destinationZ": 100.0,
+///     ...     "maxTicks": 1000, "maxDistance": 200.0,
+///     ...     "version": "Post1212", "dimension": "Overworld"
+///     ... }
+///     >>> result = calculate_group_tnt_amount(json.dumps(input_data))
+///     >>> results = json.loads(result)
+#[pyfunction]
+fn calculate_group_tnt_amount(input_json: String) -> PyResult<String> {
+    let input: GroupCalculationInput = serde_json::from_str(&input_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid input JSON: {}", e))
+    })?;
+
+    let results = api::calculate_group_tnt_amount(input).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Chunk path calculation failed: {}", e))
     })?;
 
     serde_json::to_string(&results).map_err(|e| {
@@ -47,6 +92,14 @@ fn calculate_tnt_amount(input_json: String) -> PyResult<String> {
 
 /// Calculate pearl trace with specific TNT configuration
 ///
+/// An optional ``chunkLoading`` object, either ``{"loadedChunks": [{"x", "z"}]}``
+/// or ``{"loaders": [{"x", "z"}], "simulationDistance": n}``, stops pearls
+/// before 1.21.2 in chunks that don't tick entities. The result then has
+/// status ``"Frozen"`` and the tick and chunk under ``frozen``.
+///
+/// ``entityKind`` simulates something other than a pearl with the same
+/// launch: ``Pearl`` (default), ``Tnt``, ``FallingBlock`` or ``Player``.
+///
 /// Args:
 ///     input_json (str): JSON string containing trace input parameters
 ///
@@ -68,7 +121,8 @@ fn calculate_tnt_amount(input_json: String) -> PyResult<String> {
 ///     ...     "defaultRedDirection": "NorthWest",
 ///     ...     "defaultBlueDirection": "NorthEast",
 ///     ...     "destinationX": 100.0, "destinationY": None, "destinationZ": 100.0,
-///     ...     "direction": None, "version": "Post1212"
+///     ...     "dimension": "Overworld", "direction": None, "version": "Post1212",
+///     ...     "redTntOverride": None, "blueTntOverride": None
 ///     ... }
 ///     >>> result = calculate_pearl_trace(json.dumps(input_data))
 ///     >>> trace = json.loads(result)
@@ -89,6 +143,15 @@ fn calculate_pearl_trace(input_json: String) -> PyResult<String> {
 
 /// Calculate raw pearl trace with custom TNT groups
 ///
+/// Groups with a ``fuse`` are spawned as primed TNT and explode in tick
+/// ``fuse``, re-boosting the pearl before it moves in that tick, so trace point
+/// ``fuse`` is the first one the boost changes. Groups without one (or with
+/// ``fuse`` 0) are applied to the launch motion. A group's ``source`` picks
+/// what explodes: ``Tnt`` (default), ``EndCrystal``, ``Bed``, ``RespawnAnchor``,
+/// ``TntMinecart`` (with ``speed``), ``Creeper`` (with ``charged``),
+/// ``WindCharge`` or ``BreezeWindCharge``. ``entityKind`` works as in
+/// ``calculate_pearl_trace``.
+///
 /// Args:
 ///     input_json (str): JSON string containing raw trace input parameters
 ///
@@ -103,7 +166,10 @@ fn calculate_pearl_trace(input_json: String) -> PyResult<String> {
 ///     ...     "pearlMotionX": 0.0, "pearlMotionY": 0.0, "pearlMotionZ": 0.0,
 ///     ...     "tntGroups": [
 ///     ...         {"x": -1.0, "y": 0.0, "z": -1.0, "amount": 10},
-///     ...         {"x": 1.0, "y": 0.0, "z": -1.0, "amount": 5}
+///     ...         {"x": 1.0, "y": 0.0, "z": -1.0, "amount": 5},
+///     ...         {"x": 0.0, "y": 20.0, "z": 40.0, "amount": 8, "fuse": 30},
+///     ...         {"x": 2.0, "y": 0.0, "z": 0.0, "amount": 1,
+///     ...          "source": {"kind": "EndCrystal"}}
 ///     ...     ],
 ///     ...     "version": "Post1212"
 ///     ... }
@@ -124,6 +190,231 @@ fn calculate_raw_trace(input_json: String) -> PyResult<String> {
     })
 }
 
+/// Calculate the chunks and region files a trace passes through
+///
+/// For each chunk, gives the ticks the pearl ends inside it and where the
+/// path enters and leaves it, plus loader chunks that keep every chunk on the
+/// path entity-ticking at ``simulationDistance``.
+///
+/// Args:
+///     input_json (str): JSON string with ``trace`` (points with x, y, z, such
+///         as a pearl trace) and ``simulationDistance``
+///
+/// Returns:
+///     str: JSON string containing the chunk path report
+///
+/// Example:
+///     >>> import json
+///     >>> from pearl_calculator_python import calculate_chunk_path
+///     >>> input_data = {
+///     ...     "trace": [{"x": 0.0, "y": 100.0, "z": 0.0}, {"x": 60.0, "y": 98.0, "z": 5.0}],
+///     ...     "simulationDistance": 10
+///     ... }
+///     >>> result = calculate_chunk_path(json.dumps(input_data))
+///     >>> report = json.loads(result)
+#[pyfunction]
+fn calculate_chunk_path(input_json: String) -> PyResult<String> {
+    let input: ChunkPathInput = serde_json::from_str(&input_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid input JSON: {}", e))
+    })?;
+
+    let result = api::calculate_chunk_path(input).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Chunk path calculation failed: {}", e))
+    })?;
+
+    serde_json::to_string(&result).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize result: {}", e))
+    })
+}
+
+/// Calculate the yaw and pitch for throwing a pearl by hand
+///
+/// Returns one result per arc (``"Low"`` first, then ``"High"``) that reaches the
+/// destination, which is where the pearl should come down, such as the top of a
+/// block. ``yaw`` and ``pitch`` are rounded to the tenth of a degree the F3 screen
+/// shows and can be set exactly with ``/tp``. The eyes are ``eyeHeight`` (default
+/// 1.62) above the player's feet, and ``playerMotion`` is added to the pearl, its
+/// vertical part only when ``onGround`` is false. The random spread of a throw is
+/// not included.
+///
+/// Args:
+///     input_json (str): JSON string containing throw input parameters
+///
+/// Returns:
+///     str: JSON string containing a list of throw results
+///
+/// Example:
+///     >>> import json
+///     >>> from pearl_calculator_python import calculate_throw
+///     >>> input_data = {
+///     ...     "playerX": 0.5, "playerY": 64.0, "playerZ": 0.5,
+///     ...     "destinationX": 30.0, "destinationY": 70.0, "destinationZ": 10.0,
+///     ...     "dimension": "Overworld", "maxTicks": 1000, "version": "Post1212"
+///     ... }
+///     >>> result = calculate_throw(json.dumps(input_data))
+///     >>> throws = json.loads(result)
+#[pyfunction]
+fn calculate_throw(input_json: String) -> PyResult<String> {
+    let input: ThrowInput = serde_json::from_str(&input_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid input JSON: {}", e))
+    })?;
+
+    let result = api::calculate_throw(input).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Throw calculation failed: {}", e))
+    })?;
+
+    serde_json::to_string(&result).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize result: {}", e))
+    })
+}
+
+/// Calculate the pearl a dispenser shoots
+///
+/// Builds the pearl state for a dispenser at block ``x``, ``y``, ``z`` whose front
+/// faces ``facing`` (``Down``, ``Up``, ``North``, ``South``, ``West`` or ``East``).
+/// ``mean`` has the random spread averaged out, and ``samples`` holds that many
+/// pearls (at most 100000) with the spread applied, drawn from a Java ``Random``
+/// seeded with ``seed``.
+/// Each pearl's ``offset`` is the dispenser's column and its ``position`` is relative
+/// to it, matching a cannon's ``pearl*`` and ``offset*`` inputs. Vanilla dispensers
+/// drop ender pearls instead of shooting them; this follows how they shoot snowballs.
+///
+/// Args:
+///     input_json (str): JSON string containing dispenser input parameters
+///
+/// Returns:
+///     str: JSON string containing the mean pearl and the samples
+///
+/// Example:
+///     >>> import json
+///     >>> from pearl_calculator_python import calculate_dispensed_pearl
+///     >>> input_data = {
+///     ...     "x": 10, "y": 64, "z": -3, "facing": "North",
+///     ...     "samples": 100, "seed": 7
+///     ... }
+///     >>> result = calculate_dispensed_pearl(json.dumps(input_data))
+///     >>> pearl = json.loads(result)["mean"]
+#[pyfunction]
+fn calculate_dispensed_pearl(input_json: String) -> PyResult<String> {
+    let input: DispenserInput = serde_json::from_str(&input_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid input JSON: {}", e))
+    })?;
+
+    let result = api::calculate_dispensed_pearl(input).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Dispensed pearl calculation failed: {}", e))
+    })?;
+
+    serde_json::to_string(&result).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize result: {}", e))
+    })
+}
+
+/// Calculate the landing distribution of a TNT configuration
+///
+/// Takes the same input as ``calculate_pearl_trace`` and replays the shot
+/// ``samples`` times (default 1000, at most 100000), each TNT exploding where
+/// the random kick primed TNT gets on ignition carried it during ``fuse`` ticks
+/// (default 80). ``spawnSpread`` adds a gaussian offset with that standard deviation to every
+/// TNT, for dupers that don't spawn them in one spot. Samples come from a Java
+/// ``Random`` seeded with ``seed`` (default 0), so the same seed gives the same
+/// result. The result has the landing ``mean``, ``covariance``, 95% confidence
+/// ``ellipse`` and the share of landings within ``hitRadius`` (default 0.5) of
+/// the destination. Every sample flies until it lands; one that never does is
+/// taken at ``tick``, where the nominal shot lands or passes closest to the
+/// destination.
+///
+/// Args:
+///     input_json (str): JSON string containing trace and sampling parameters
+///
+/// Returns:
+///     str: JSON string containing the landing statistics
+///
+/// Example:
+///     >>> import json
+///     >>> from pearl_calculator_python import calculate_landing_distribution
+///     >>> input_data = {
+///     ...     "redTnt": 10, "blueTnt": 5, "verticalTntAmount": None,
+///     ...     "pearlX": 0.0, "pearlY": 0.0, "pearlZ": 0.0,
+///     ...     "pearlMotionX": 0.0, "pearlMotionY": 0.0, "pearlMotionZ": 0.0,
+///     ...     "offsetX": 0.0, "offsetZ": 0.0, "cannonY": 0.0,
+///     ...     "northWestTnt": {"x": -1.0, "y": 0.0, "z": -1.0},
+///     ...     "northEastTnt": {"x": 1.0, "y": 0.0, "z": -1.0},
+///     ...     "southWestTnt": {"x": -1.0, "y": 0.0, "z": 1.0},
+///     ...     "southEastTnt": {"x": 1.0, "y": 0.0, "z": 1.0},
+///     ...     "defaultRedDirection": "NorthWest",
+///     ...     "defaultBlueDirection": "NorthEast",
+///     ...     "destinationX": 100.0, "destinationY": None, "destinationZ": 100.0,
+///     ...     "direction": None, "version": "Post1212",
+///     ...     "samples": 500, "seed": 42, "hitRadius": 1.0
+///     ... }
+///     >>> result = calculate_landing_distribution(json.dumps(input_data))
+///     >>> chance = json.loads(result)["hit_probability"]
+#[pyfunction]
+fn calculate_landing_distribution(input_json: String) -> PyResult<String> {
+    let input: LandingDistributionInput = serde_json::from_str(&input_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid input JSON: {}", e))
+    })?;
+
+    let result = api::calculate_landing_distribution(input).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Landing distribution calculation failed: {}", e))
+    })?;
+
+    serde_json::to_string(&result).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize result: {}", e))
+    })
+}
+
+/// Calculate how sensitive a TNT result's landing point is to its inputs
+///
+/// Takes the same input as ``calculate_pearl_trace`` plus the ``tick`` of the
+/// result being checked, and flies the shot again with each input nudged. ``red``,
+/// ``blue`` and ``vertical`` hold the landing displacement from one TNT ``more``
+/// and one ``fewer`` in that group (``fewer`` is null for an empty group and
+/// ``vertical`` is null without vertical TNT). ``position`` and ``motion`` are the
+/// landing displacement per unit of error in the pearl's start position and launch
+/// motion, one entry per axis, so together they form the Jacobian of the landing
+/// point.
+///
+/// Args:
+///     input_json (str): JSON string containing trace input parameters and tick
+///
+/// Returns:
+///     str: JSON string containing the landing displacements
+///
+/// Example:
+///     >>> import json
+///     >>> from pearl_calculator_python import calculate_sensitivity
+///     >>> input_data = {
+///     ...     "redTnt": 10, "blueTnt": 5, "verticalTntAmount": None,
+///     ...     "pearlX": 0.0, "pearlY": 0.0, "pearlZ": 0.0,
+///     ...     "pearlMotionX": 0.0, "pearlMotionY": 0.0, "pearlMotionZ": 0.0,
+///     ...     "offsetX": 0.0, "offsetZ": 0.0, "cannonY": 0.0,
+///     ...     "northWestTnt": {"x": -1.0, "y": 0.0, "z": -1.0},
+///     ...     "northEastTnt": {"x": 1.0, "y": 0.0, "z": -1.0},
+///     ...     "southWestTnt": {"x": -1.0, "y": 0.0, "z": 1.0},
+///     ...     "southEastTnt": {"x": 1.0, "y": 0.0, "z": 1.0},
+///     ...     "defaultRedDirection": "NorthWest",
+///     ...     "defaultBlueDirection": "NorthEast",
+///     ...     "destinationX": 100.0, "destinationY": None, "destinationZ": 100.0,
+///     ...     "direction": None, "version": "Post1212", "tick": 20
+///     ... }
+///     >>> result = calculate_sensitivity(json.dumps(input_data))
+///     >>> red_step = json.loads(result)["red"]["more"]
+#[pyfunction]
+fn calculate_sensitivity(input_json: String) -> PyResult<String> {
+    let input: SensitivityInput = serde_json::from_str(&input_json).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid input JSON: {}", e))
+    })?;
+
+    let result = api::calculate_sensitivity(input).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Sensitivity calculation failed: {}", e))
+    })?;
+
+    serde_json::to_string(&result).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize result: {}", e))
+    })
+}
+
 /// PearlCalculatorRS Python bindings
 ///
 /// This module provides Python bindings for the PearlCalculatorRS core library,
@@ -132,7 +423,13 @@ fn calculate_raw_trace(input_json: String) -> PyResult<String> {
 #[pymodule]
 fn pearl_calculator_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(calculate_tnt_amount, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_group_tnt_amount, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_pearl_trace, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_raw_trace, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_chunk_path, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_throw, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_dispensed_pearl, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_landing_distribution, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_sensitivity, m)?)?;
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,12 @@
 use pearl_calculator_bridge::api;
-use pearl_calculator_bridge::inputs::{CalculationInput, PearlTraceInput, RawTraceInput};
-use pearl_calculator_bridge::outputs::PearlTraceOutput;
+use pearl_calculator_bridge::inputs::{
+    CalculationInput, ChunkPathInput, DispenserInput, GroupCalculationInput,
+    LandingDistributionInput, PearlTraceInput, RawTraceInput, SensitivityInput, ThrowInput,
+};
+use pearl_calculator_bridge::outputs::{
+    ChunkPathOutput, DispensedPearlOutput, LandingDistributionOutput, PearlTraceOutput,
+    SensitivityOutput, ThrowResultOutput,
+};
 
 #[tauri::command]
 pub fn calculate_tnt_amount_command(input: CalculationInput) -> Result<serde_json::Value, String> {
@@ -8,6 +14,14 @@ pub fn calculate_tnt_amount_command(input: CalculationInput) -> Result<serde_jso
     serde_json::to_value(&results).map_err(|e| format!("Serialization error: {}", e))
 }
 
+#[tauri::command]
+pub fn calculate_group_tnt_amount_command(
+    input: GroupCalculationInput,
+) -> Result<serde_json::Value, String> {
+    let results = api::calculate_group_tnt_amount(input)?;
+    serde_json::to_value(&results).map_err(|e| format!("Serialization error: {}", e))
+}
+
 #[tauri::command]
 pub fn calculate_pearl_trace_command(input: PearlTraceInput) -> Result<PearlTraceOutput, String> {
     api::calculate_pearl_trace(input)
@@ -17,3 +31,32 @@ pub fn calculate_pearl_trace_command(input: PearlTraceInput) -> Result<PearlTrac
 pub fn calculate_raw_trace_command(input: RawTraceInput) -> Result<PearlTraceOutput, String> {
     api::calculate_raw_trace(input)
 }
+
+#[tauri::command]
+pub fn calculate_chunk_path_command(input: ChunkPathInput) -> Result<ChunkPathOutput, String> {
+    api::calculate_chunk_path(input)
+}
+
+#[tauri::command]
+pub fn calculate_throw_command(input: ThrowInput) -> Result<Vec<ThrowResultOutput>, String> {
+    api::calculate_throw(input)
+}
+
+#[tauri::command]
+pub fn calculate_dispensed_pearl_command(
+    input: DispenserInput,
+) -> Result<DispensedPearlOutput, String> {
+    api::calculate_dispensed_pearl(input)
+}
+
+#[tauri::command]
+pub fn calculate_landing_distribution_command(
+    input: LandingDistributionInput,
+) -> Result<LandingDistributionOutput, String> {
+    api::calculate_landing_distribution(input)
+}
+
+#[tauri::command]
+pub fn calculate_sensitivity_command(input: SensitivityInput) -> Result<SensitivityOutput, String> {
+    api::calculate_sensitivity(input)
+}
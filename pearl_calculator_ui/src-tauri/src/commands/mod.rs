@@ -2,6 +2,9 @@ pub mod calculation;
 pub mod config;
 
 pub use calculation::{
-    calculate_pearl_trace_command, calculate_raw_trace_command, calculate_tnt_amount_command,
+    calculate_chunk_path_command, calculate_dispensed_pearl_command,
+    calculate_group_tnt_amount_command, calculate_landing_distribution_command,
+    calculate_pearl_trace_command, calculate_raw_trace_command, calculate_sensitivity_command,
+    calculate_throw_command, calculate_tnt_amount_command,
 };
 pub use config::{load_config, load_config_from_content, verify_config};
@@ -1,8 +1,11 @@
 mod commands;
 
 use commands::{
-    calculate_pearl_trace_command, calculate_raw_trace_command, calculate_tnt_amount_command,
-    load_config, load_config_from_content, verify_config,
+    calculate_chunk_path_command, calculate_dispensed_pearl_command,
+    calculate_group_tnt_amount_command, calculate_landing_distribution_command,
+    calculate_pearl_trace_command, calculate_raw_trace_command, calculate_sensitivity_command,
+    calculate_throw_command, calculate_tnt_amount_command, load_config, load_config_from_content,
+    verify_config,
 };
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -17,8 +20,14 @@ pub fn run() {
             load_config,
             load_config_from_content,
             calculate_tnt_amount_command,
+            calculate_group_tnt_amount_command,
             calculate_pearl_trace_command,
-            calculate_raw_trace_command
+            calculate_raw_trace_command,
+            calculate_chunk_path_command,
+            calculate_throw_command,
+            calculate_dispensed_pearl_command,
+            calculate_landing_distribution_command,
+            calculate_sensitivity_command
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
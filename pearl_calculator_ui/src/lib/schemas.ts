@@ -15,6 +15,8 @@ export const TNTDirectionSchema = z.enum([
 	"NorthEast",
 ]);
 
+export const DimensionSchema = z.enum(["Overworld", "Nether", "End"]);
+
 export const BitDirectionSchema = z.enum(["North", "East", "West", "South"]);
 
 export const CoercedNumberSchema = z.union([
@@ -37,6 +39,17 @@ export const CoercedVector3Schema = z.object({
 	z: CoercedNumberSchema,
 });
 
+export const ArrivalSchema = z.object({
+	teleport_position: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	feet_position: z.object({ X: z.number(), Y: z.number(), Z: z.number() }).nullable(),
+	feet_block: Vector3Schema.nullable(),
+	fall_distance: z.number().nullable(),
+	damage: z.number(),
+	survivable: z.boolean(),
+	suffocating: z.boolean(),
+	into_void: z.boolean(),
+});
+
 export const TNTResultSchema = z.object({
 	distance: z.number(),
 	tick: z.number(),
@@ -48,6 +61,26 @@ export const TNTResultSchema = z.object({
 	direction: z.string(),
 	vertical: z.number().optional(),
 	charges: z.number().optional(),
+	conversion: z
+		.object({
+			dimension: DimensionSchema,
+			pearl_end_pos: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+			warning: z.string().nullable(),
+		})
+		.nullish(),
+	arrival: ArrivalSchema.nullish(),
+});
+
+export const GroupTNTResultSchema = z.object({
+	distance: z.number(),
+	tick: z.number(),
+	counts: z.array(z.object({ name: z.string(), count: z.number() })),
+	yaw: z.number(),
+	pitch: z.number(),
+	total: z.number(),
+	pearl_end_pos: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	pearl_end_motion: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	direction: z.string(),
 });
 
 export const PearlTraceResultSchema = z.object({
@@ -69,6 +102,88 @@ export const PearlTraceResultSchema = z.object({
 			distance: z.number(),
 		})
 		.nullish(),
+	fluid_ticks: z
+		.array(z.object({ tick: z.number(), fluid: z.string() }))
+		.optional(),
+	status: z.enum(["InFlight", "Landed", "RemovedInVoid", "Frozen"]).optional(),
+	frozen: z
+		.object({ tick: z.number(), chunk_x: z.number(), chunk_z: z.number() })
+		.nullish(),
+	arrival: ArrivalSchema.nullish(),
+});
+
+export const ThrowResultSchema = z.object({
+	arc: z.enum(["Low", "High"]),
+	yaw: z.number(),
+	pitch: z.number(),
+	pearl_start_pos: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	pearl_start_motion: z.object({
+		X: z.number(),
+		Y: z.number(),
+		Z: z.number(),
+	}),
+	tick: z.number(),
+	pearl_end_pos: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	distance: z.number(),
+	status: z.enum(["InFlight", "Landed", "RemovedInVoid", "Frozen"]),
+});
+
+const PearlStateSchema = z.object({
+	position: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	motion: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	offset: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+});
+
+export const DispensedPearlResultSchema = z.object({
+	mean: PearlStateSchema,
+	samples: z.array(PearlStateSchema),
+});
+
+export const LandingDistributionResultSchema = z.object({
+	tick: z.number(),
+	landings: z.array(z.object({ X: z.number(), Y: z.number(), Z: z.number() })),
+	mean: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	covariance: z.array(z.array(z.number())),
+	ellipse: z.object({
+		semi_major: z.number(),
+		semi_minor: z.number(),
+		angle: z.number(),
+	}),
+	hit_probability: z.number(),
+});
+
+const TntSensitivitySchema = z.object({
+	more: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	fewer: z.object({ X: z.number(), Y: z.number(), Z: z.number() }).nullable(),
+});
+
+export const SensitivityResultSchema = z.object({
+	tick: z.number(),
+	landing: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+	red: TntSensitivitySchema,
+	blue: TntSensitivitySchema,
+	vertical: TntSensitivitySchema.nullable(),
+	position: z.array(z.object({ X: z.number(), Y: z.number(), Z: z.number() })),
+	motion: z.array(z.object({ X: z.number(), Y: z.number(), Z: z.number() })),
+});
+
+export const ChunkPathResultSchema = z.object({
+	chunks: z.array(
+		z.object({
+			chunk_x: z.number(),
+			chunk_z: z.number(),
+			region_x: z.number(),
+			region_z: z.number(),
+			first_tick: z.number(),
+			last_tick: z.number(),
+			entry: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+			exit: z.object({ X: z.number(), Y: z.number(), Z: z.number() }),
+		}),
+	),
+	regions: z.array(
+		z.object({ x: z.number(), z: z.number(), file: z.string() }),
+	),
+	loaders: z.array(z.object({ chunk_x: z.number(), chunk_z: z.number() })),
 });
 
 export const TraceTNTSchema = z.object({
@@ -1,4 +1,13 @@
-import type { PearlTraceResult, TNTResult } from "@/types/domain";
+import type {
+	ChunkPathResult,
+	DispensedPearlResult,
+	GroupTNTResult,
+	LandingDistributionResult,
+	PearlTraceResult,
+	SensitivityResult,
+	TNTResult,
+	ThrowResult,
+} from "@/types/domain";
 
 export interface Space3DInput {
 	x: number;
@@ -20,19 +29,29 @@ export interface CalculationInput {
 	northEastTnt: Space3DInput;
 	southWestTnt: Space3DInput;
 	southEastTnt: Space3DInput;
+	northTnt?: Space3DInput;
+	southTnt?: Space3DInput;
+	westTnt?: Space3DInput;
+	eastTnt?: Space3DInput;
 	defaultRedDirection: string;
 	defaultBlueDirection: string;
 	destinationX: number;
 	destinationY?: number;
 	destinationZ: number;
+	dimension?: string;
+	destinationDimension?: string;
 	maxTnt: number;
 	maxTicks: number;
 	maxDistance: number;
 	version: string;
 	verticalTnt?: Space3DInput;
 	mode?: string;
+	redTntOverride?: Space3DInput;
+	blueTntOverride?: Space3DInput;
 }
 
+export type EntityKind = "Pearl" | "Tnt" | "FallingBlock" | "Player";
+
 export interface PearlTraceInput {
 	redTnt: number;
 	blueTnt: number;
@@ -50,14 +69,76 @@ export interface PearlTraceInput {
 	northEastTnt: Space3DInput;
 	southWestTnt: Space3DInput;
 	southEastTnt: Space3DInput;
+	northTnt?: Space3DInput;
+	southTnt?: Space3DInput;
+	westTnt?: Space3DInput;
+	eastTnt?: Space3DInput;
 	defaultRedDirection: string;
 	defaultBlueDirection: string;
 	destinationX: number;
 	destinationZ: number;
+	dimension?: string;
 	direction?: string;
 	version: string;
 	verticalTnt?: Space3DInput;
 	mode?: string;
+	redTntOverride?: Space3DInput;
+	blueTntOverride?: Space3DInput;
+	chunkLoading?: ChunkLoadingInput;
+	entityKind?: EntityKind;
+}
+
+export interface ChunkPathInput {
+	trace: Space3DInput[];
+	simulationDistance: number;
+}
+
+export interface ThrowInput {
+	playerX: number;
+	playerY: number;
+	playerZ: number;
+	eyeHeight?: number;
+	playerMotion?: Space3DInput;
+	onGround?: boolean;
+	destinationX: number;
+	destinationY: number;
+	destinationZ: number;
+	dimension?: string;
+	maxTicks: number;
+	version: string;
+	chunkLoading?: ChunkLoadingInput;
+}
+
+export interface DispenserInput {
+	x: number;
+	y: number;
+	z: number;
+	facing: "Down" | "Up" | "North" | "South" | "West" | "East";
+	samples?: number;
+	seed?: number;
+}
+
+export interface LandingDistributionInput extends PearlTraceInput {
+	samples?: number;
+	seed?: number;
+	fuse?: number;
+	spawnSpread?: number;
+	hitRadius?: number;
+}
+
+export interface SensitivityInput extends PearlTraceInput {
+	tick: number;
+}
+
+export interface ChunkInput {
+	x: number;
+	z: number;
+}
+
+export interface ChunkLoadingInput {
+	loadedChunks?: ChunkInput[];
+	loaders?: ChunkInput[];
+	simulationDistance?: number;
 }
 
 export interface TntGroupInput {
@@ -67,6 +148,22 @@ export interface TntGroupInput {
 	charges?: number;
 	z: number;
 	amount: number;
+	fuse?: number;
+	source?: ExplosionSourceInput;
+}
+
+export interface ExplosionSourceInput {
+	kind:
+		| "Tnt"
+		| "EndCrystal"
+		| "Bed"
+		| "RespawnAnchor"
+		| "TntMinecart"
+		| "Creeper"
+		| "WindCharge"
+		| "BreezeWindCharge";
+	speed?: number;
+	charged?: boolean;
 }
 
 export interface RawTraceInput {
@@ -77,13 +174,58 @@ export interface RawTraceInput {
 	pearlMotionY: number;
 	pearlMotionZ: number;
 	tntGroups: TntGroupInput[];
+	dimension?: string;
+	version: string;
+	chunkLoading?: ChunkLoadingInput;
+	entityKind?: EntityKind;
+}
+
+export interface CannonGroupInput {
+	name: string;
+	x: number;
+	y: number;
+	z: number;
+	maxCount?: number;
+	vertical?: boolean;
+	source?: ExplosionSourceInput;
+}
+
+export interface GroupCalculationInput {
+	pearlX: number;
+	pearlY: number;
+	pearlZ: number;
+	pearlMotionX: number;
+	pearlMotionY: number;
+	pearlMotionZ: number;
+	offsetX: number;
+	offsetZ: number;
+	cannonY: number;
+	groups: CannonGroupInput[];
+	destinationX: number;
+	destinationY?: number;
+	destinationZ: number;
+	maxTicks: number;
+	maxDistance: number;
 	version: string;
+	dimension?: string;
 }
 
 export interface ICalculatorService {
 	calculateTNTAmount(input: CalculationInput): Promise<TNTResult[]>;
+	calculateGroupTNTAmount(
+		input: GroupCalculationInput,
+	): Promise<GroupTNTResult[]>;
 	calculatePearlTrace(input: PearlTraceInput): Promise<PearlTraceResult>;
 	calculateRawTrace(input: RawTraceInput): Promise<PearlTraceResult>;
+	calculateChunkPath(input: ChunkPathInput): Promise<ChunkPathResult>;
+	calculateThrow(input: ThrowInput): Promise<ThrowResult[]>;
+	calculateDispensedPearl(
+		input: DispenserInput,
+	): Promise<DispensedPearlResult>;
+	calculateLandingDistribution(
+		input: LandingDistributionInput,
+	): Promise<LandingDistributionResult>;
+	calculateSensitivity(input: SensitivityInput): Promise<SensitivityResult>;
 	copyToClipboard(text: string): Promise<void>;
 	readFromClipboard(): Promise<string>;
 }
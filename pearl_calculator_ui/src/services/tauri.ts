@@ -1,13 +1,37 @@
 import { invoke } from "@tauri-apps/api/core";
 import { writeText, readText } from "@tauri-apps/plugin-clipboard-manager";
-import type { PearlTraceResult, TNTResult } from "@/types/domain";
-import { PearlTraceResultSchema, TNTResultSchema } from "@/lib/schemas";
+import type {
+	ChunkPathResult,
+	DispensedPearlResult,
+	GroupTNTResult,
+	LandingDistributionResult,
+	PearlTraceResult,
+	SensitivityResult,
+	TNTResult,
+	ThrowResult,
+} from "@/types/domain";
+import {
+	ChunkPathResultSchema,
+	DispensedPearlResultSchema,
+	GroupTNTResultSchema,
+	LandingDistributionResultSchema,
+	PearlTraceResultSchema,
+	SensitivityResultSchema,
+	TNTResultSchema,
+	ThrowResultSchema,
+} from "@/lib/schemas";
 import { z } from "zod";
 import type {
 	CalculationInput,
+	ChunkPathInput,
+	DispenserInput,
+	GroupCalculationInput,
 	ICalculatorService,
+	LandingDistributionInput,
 	PearlTraceInput,
 	RawTraceInput,
+	SensitivityInput,
+	ThrowInput,
 } from "./interface";
 
 export class TauriCalculatorService implements ICalculatorService {
@@ -16,6 +40,15 @@ export class TauriCalculatorService implements ICalculatorService {
 		return z.array(TNTResultSchema).parse(result);
 	}
 
+	async calculateGroupTNTAmount(
+		input: GroupCalculationInput,
+	): Promise<GroupTNTResult[]> {
+		const result = await invoke("calculate_group_tnt_amount_command", {
+			input,
+		});
+		return z.array(GroupTNTResultSchema).parse(result);
+	}
+
 	async calculatePearlTrace(input: PearlTraceInput): Promise<PearlTraceResult> {
 		const result = await invoke("calculate_pearl_trace_command", { input });
 		return PearlTraceResultSchema.parse(result);
@@ -26,6 +59,41 @@ export class TauriCalculatorService implements ICalculatorService {
 		return PearlTraceResultSchema.parse(result);
 	}
 
+	async calculateChunkPath(input: ChunkPathInput): Promise<ChunkPathResult> {
+		const result = await invoke("calculate_chunk_path_command", { input });
+		return ChunkPathResultSchema.parse(result);
+	}
+
+	async calculateThrow(input: ThrowInput): Promise<ThrowResult[]> {
+		const result = await invoke("calculate_throw_command", { input });
+		return z.array(ThrowResultSchema).parse(result);
+	}
+
+	async calculateDispensedPearl(
+		input: DispenserInput,
+	): Promise<DispensedPearlResult> {
+		const result = await invoke("calculate_dispensed_pearl_command", {
+			input,
+		});
+		return DispensedPearlResultSchema.parse(result);
+	}
+
+	async calculateLandingDistribution(
+		input: LandingDistributionInput,
+	): Promise<LandingDistributionResult> {
+		const result = await invoke("calculate_landing_distribution_command", {
+			input,
+		});
+		return LandingDistributionResultSchema.parse(result);
+	}
+
+	async calculateSensitivity(
+		input: SensitivityInput,
+	): Promise<SensitivityResult> {
+		const result = await invoke("calculate_sensitivity_command", { input });
+		return SensitivityResultSchema.parse(result);
+	}
+
 	async copyToClipboard(text: string): Promise<void> {
 		await writeText(text);
 	}
@@ -1,15 +1,38 @@
-import type { PearlTraceResult, TNTResult } from "@/types/domain";
+import type {
+	ChunkPathResult,
+	DispensedPearlResult,
+	GroupTNTResult,
+	LandingDistributionResult,
+	PearlTraceResult,
+	SensitivityResult,
+	TNTResult,
+	ThrowResult,
+} from "@/types/domain";
 import type {
 	CalculationInput,
+	ChunkPathInput,
+	DispenserInput,
+	GroupCalculationInput,
 	ICalculatorService,
+	LandingDistributionInput,
 	PearlTraceInput,
 	RawTraceInput,
+	SensitivityInput,
+	ThrowInput,
 } from "./interface";
 
 interface PearlCalculatorWasm {
 	calculate_tnt_amount(input: CalculationInput): TNTResult[];
+	calculate_group_tnt_amount(input: GroupCalculationInput): GroupTNTResult[];
 	calculate_pearl_trace(input: PearlTraceInput): PearlTraceResult;
 	calculate_raw_trace(input: RawTraceInput): PearlTraceResult;
+	calculate_chunk_path(input: ChunkPathInput): ChunkPathResult;
+	calculate_throw(input: ThrowInput): ThrowResult[];
+	calculate_dispensed_pearl(input: DispenserInput): DispensedPearlResult;
+	calculate_landing_distribution(
+		input: LandingDistributionInput,
+	): LandingDistributionResult;
+	calculate_sensitivity(input: SensitivityInput): SensitivityResult;
 }
 
 export class WebCalculatorService implements ICalculatorService {
@@ -20,6 +43,15 @@ export class WebCalculatorService implements ICalculatorService {
 		return (await wasm).calculate_tnt_amount(input);
 	}
 
+	async calculateGroupTNTAmount(
+		input: GroupCalculationInput,
+	): Promise<GroupTNTResult[]> {
+		const wasm = (await import(
+			"pearl_calculator_wasm"
+		)) as unknown as Promise<PearlCalculatorWasm>;
+		return (await wasm).calculate_group_tnt_amount(input);
+	}
+
 	async calculatePearlTrace(input: PearlTraceInput): Promise<PearlTraceResult> {
 		const wasm = (await import(
 			"pearl_calculator_wasm"
@@ -34,6 +66,47 @@ export class WebCalculatorService implements ICalculatorService {
 		return (await wasm).calculate_raw_trace(input);
 	}
 
+	async calculateChunkPath(input: ChunkPathInput): Promise<ChunkPathResult> {
+		const wasm = (await import(
+			"pearl_calculator_wasm"
+		)) as unknown as Promise<PearlCalculatorWasm>;
+		return (await wasm).calculate_chunk_path(input);
+	}
+
+	async calculateThrow(input: ThrowInput): Promise<ThrowResult[]> {
+		const wasm = (await import(
+			"pearl_calculator_wasm"
+		)) as unknown as Promise<PearlCalculatorWasm>;
+		return (await wasm).calculate_throw(input);
+	}
+
+	async calculateDispensedPearl(
+		input: DispenserInput,
+	): Promise<DispensedPearlResult> {
+		const wasm = (await import(
+			"pearl_calculator_wasm"
+		)) as unknown as Promise<PearlCalculatorWasm>;
+		return (await wasm).calculate_dispensed_pearl(input);
+	}
+
+	async calculateLandingDistribution(
+		input: LandingDistributionInput,
+	): Promise<LandingDistributionResult> {
+		const wasm = (await import(
+			"pearl_calculator_wasm"
+		)) as unknown as Promise<PearlCalculatorWasm>;
+		return (await wasm).calculate_landing_distribution(input);
+	}
+
+	async calculateSensitivity(
+		input: SensitivityInput,
+	): Promise<SensitivityResult> {
+		const wasm = (await import(
+			"pearl_calculator_wasm"
+		)) as unknown as Promise<PearlCalculatorWasm>;
+		return (await wasm).calculate_sensitivity(input);
+	}
+
 	async copyToClipboard(text: string): Promise<void> {
 		await navigator.clipboard.writeText(text);
 	}
@@ -1,19 +1,26 @@
 import { z } from "zod";
 import {
+	ArrivalSchema,
 	BitCalculationResultSchema,
 	BitDirectionSchema,
 	BitInputStateSchema,
 	BitTemplateConfigSchema,
 	CalculatorInputsSchema,
 	CannonModeSchema,
+	ChunkPathResultSchema,
+	DispensedPearlResultSchema,
 	GeneralConfigSchema,
+	GroupTNTResultSchema,
+	LandingDistributionResultSchema,
 	MaskGroupSchema,
 	MultiplierBitInputStateSchema,
 	MultiplierConfigSchema,
 	PearlTraceResultSchema,
 	PearlVersionSchema,
+	SensitivityResultSchema,
 	SimulatorConfigSchema,
 	TNTResultSchema,
+	ThrowResultSchema,
 	TraceTNTSchema,
 } from "@/lib/schemas";
 
@@ -23,8 +30,24 @@ export type CannonMode = z.infer<typeof CannonModeSchema>;
 
 export type TNTResult = z.infer<typeof TNTResultSchema>;
 
+export type Arrival = z.infer<typeof ArrivalSchema>;
+
+export type GroupTNTResult = z.infer<typeof GroupTNTResultSchema>;
+
 export type PearlTraceResult = z.infer<typeof PearlTraceResultSchema>;
 
+export type SensitivityResult = z.infer<typeof SensitivityResultSchema>;
+
+export type LandingDistributionResult = z.infer<
+	typeof LandingDistributionResultSchema
+>;
+
+export type DispensedPearlResult = z.infer<typeof DispensedPearlResultSchema>;
+
+export type ThrowResult = z.infer<typeof ThrowResultSchema>;
+
+export type ChunkPathResult = z.infer<typeof ChunkPathResultSchema>;
+
 export type TraceTNT = z.infer<typeof TraceTNTSchema>;
 
 export type GeneralConfig = z.infer<typeof GeneralConfigSchema>;
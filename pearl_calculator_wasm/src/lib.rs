@@ -1,5 +1,8 @@
 use pearl_calculator_bridge::api;
-use pearl_calculator_bridge::inputs::{CalculationInput, PearlTraceInput, RawTraceInput};
+use pearl_calculator_bridge::inputs::{
+    CalculationInput, ChunkPathInput, DispenserInput, GroupCalculationInput,
+    LandingDistributionInput, PearlTraceInput, RawTraceInput, SensitivityInput, ThrowInput,
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -9,6 +12,13 @@ pub fn calculate_tnt_amount(val: JsValue) -> Result<JsValue, JsError> {
     Ok(serde_wasm_bindgen::to_value(&results)?)
 }
 
+#[wasm_bindgen]
+pub fn calculate_group_tnt_amount(val: JsValue) -> Result<JsValue, JsError> {
+    let input: GroupCalculationInput = serde_wasm_bindgen::from_value(val)?;
+    let results = api::calculate_group_tnt_amount(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&results)?)
+}
+
 #[wasm_bindgen]
 pub fn calculate_pearl_trace(val: JsValue) -> Result<JsValue, JsError> {
     let input: PearlTraceInput = serde_wasm_bindgen::from_value(val)?;
@@ -22,3 +32,38 @@ pub fn calculate_raw_trace(val: JsValue) -> Result<JsValue, JsError> {
     let result = api::calculate_raw_trace(input).map_err(|e| JsError::new(&e))?;
     Ok(serde_wasm_bindgen::to_value(&result)?)
 }
+
+#[wasm_bindgen]
+pub fn calculate_chunk_path(val: JsValue) -> Result<JsValue, JsError> {
+    let input: ChunkPathInput = serde_wasm_bindgen::from_value(val)?;
+    let result = api::calculate_chunk_path(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+#[wasm_bindgen]
+pub fn calculate_throw(val: JsValue) -> Result<JsValue, JsError> {
+    let input: ThrowInput = serde_wasm_bindgen::from_value(val)?;
+    let result = api::calculate_throw(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+#[wasm_bindgen]
+pub fn calculate_dispensed_pearl(val: JsValue) -> Result<JsValue, JsError> {
+    let input: DispenserInput = serde_wasm_bindgen::from_value(val)?;
+    let result = api::calculate_dispensed_pearl(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+#[wasm_bindgen]
+pub fn calculate_landing_distribution(val: JsValue) -> Result<JsValue, JsError> {
+    let input: LandingDistributionInput = serde_wasm_bindgen::from_value(val)?;
+    let result = api::calculate_landing_distribution(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+#[wasm_bindgen]
+pub fn calculate_sensitivity(val: JsValue) -> Result<JsValue, JsError> {
+    let input: SensitivityInput = serde_wasm_bindgen::from_value(val)?;
+    let result = api::calculate_sensitivity(input).map_err(|e| JsError::new(&e))?;
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}